@@ -0,0 +1,169 @@
+// Generates all_solvers() from the solutions found in src/yYYYY/dayN.rs
+//
+// A day is registered if its file exists and src/yYYYY.rs declares it with `pub mod dayN;`
+// (and src/lib.rs declares the year with `pub mod yYYYY;`)
+//
+// Also generates a test for each example input in examples/YYYY/dayN/NAME.txt
+
+use std::fs;
+use std::path::Path;
+
+fn main() {
+    println!("cargo::rerun-if-changed=src");
+    println!("cargo::rerun-if-changed=examples");
+
+    let lib_rs = fs::read_to_string("src/lib.rs").unwrap();
+    let mut years = vec![];
+    for entry in fs::read_dir("src").unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir()
+            && let Some(year) = number_after(&path, "y")
+        {
+            if lib_rs
+                .lines()
+                .any(|line| line.trim() == format!("pub mod y{};", year))
+            {
+                years.push((year, find_days(&path, year)));
+            } else {
+                println!(
+                    "cargo::warning={} is not registered: add `pub mod y{};` to src/lib.rs",
+                    path.display(),
+                    year
+                );
+            }
+        }
+    }
+    years.sort();
+
+    let mut code = String::from("// generated by build.rs\n");
+    code += "pub fn all_solvers() -> Vec<Solver> {\n";
+    code += "    vec![\n";
+    for (year, days) in years.iter().filter(|(_, days)| !days.is_empty()) {
+        let days: Vec<_> = days.iter().map(|day| format!("day{}", day)).collect();
+        code += &format!("        solvers!(y{} {}),\n", year, days.join(", "));
+    }
+    code += "    ]\n";
+    code += "    .into_iter()\n";
+    code += "    .flatten()\n";
+    code += "    .collect()\n";
+    code += "}\n";
+
+    let out_dir = std::env::var("OUT_DIR").unwrap();
+    fs::write(Path::new(&out_dir).join("solvers.rs"), code).unwrap();
+    fs::write(
+        Path::new(&out_dir).join("example_tests.rs"),
+        example_tests(&years),
+    )
+    .unwrap();
+}
+
+// a module per year and day, with a test for each example
+fn example_tests(years: &[(u32, Vec<u32>)]) -> String {
+    let mut code = String::from("// generated by build.rs\n");
+    for (year, days) in years {
+        let mut year_code = String::new();
+        for day in days {
+            let names = example_names(*year, *day);
+            if names.is_empty() {
+                continue;
+            }
+            year_code += &format!("    mod day{} {{\n", day);
+            for (name, test_name) in names.iter().zip(test_names(&names)) {
+                year_code += &format!(
+                    "        #[test]\n        fn {}() {{\n            crate::examples::test::<crate::y{}::day{}::Solution>({}, {}, {:?});\n        }}\n",
+                    test_name, year, day, year, day, name
+                );
+            }
+            year_code += "    }\n";
+        }
+        if !year_code.is_empty() {
+            code += &format!("mod y{} {{\n{}}}\n", year, year_code);
+        }
+    }
+    code
+}
+
+// names of examples/YYYY/dayN/NAME.txt files
+fn example_names(year: u32, day: u32) -> Vec<String> {
+    let Ok(entries) = fs::read_dir(format!("examples/{}/day{}", year, day)) else {
+        return vec![];
+    };
+    let mut names: Vec<_> = entries
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            if path.extension()? == "txt" {
+                Some(path.file_stem()?.to_str()?.to_string())
+            } else {
+                None
+            }
+        })
+        .collect();
+    names.sort();
+    names
+}
+
+// example names converted to valid function names.  The prefix keeps keywords (eg "match")
+// and leading digits out, and a suffix keeps names that clean up the same (eg "a-b" and
+// "a_b") apart.
+fn test_names(names: &[String]) -> Vec<String> {
+    let mut used = std::collections::HashSet::new();
+    names
+        .iter()
+        .map(|name| {
+            let clean: String = name
+                .chars()
+                .map(|c| {
+                    if c.is_ascii_alphanumeric() {
+                        c.to_ascii_lowercase()
+                    } else {
+                        '_'
+                    }
+                })
+                .collect();
+            let mut test_name = format!("example_{}", clean);
+            let mut n = 2;
+            while !used.insert(test_name.clone()) {
+                test_name = format!("example_{}_{}", clean, n);
+                n += 1;
+            }
+            test_name
+        })
+        .collect()
+}
+
+// days that have both a src/yYYYY/dayN.rs file and a `pub mod dayN;` line in src/yYYYY.rs
+fn find_days(dir: &Path, year: u32) -> Vec<u32> {
+    let year_rs = fs::read_to_string(format!("src/y{}.rs", year)).unwrap_or_default();
+    let mut days = vec![];
+    for entry in fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.extension().is_some_and(|ext| ext == "rs")
+            && let Some(day) = number_after(&path, "day")
+        {
+            if year_rs
+                .lines()
+                .any(|line| line.trim() == format!("pub mod day{};", day))
+            {
+                days.push(day);
+            } else {
+                println!(
+                    "cargo::warning={} is not registered: add `pub mod day{};` to src/y{}.rs",
+                    path.display(),
+                    day,
+                    year
+                );
+            }
+        }
+    }
+    days.sort();
+    days
+}
+
+// the number in a file stem such as "y2019" or "day12"
+fn number_after(path: &Path, prefix: &str) -> Option<u32> {
+    path.file_stem()?
+        .to_str()?
+        .strip_prefix(prefix)?
+        .parse()
+        .ok()
+}
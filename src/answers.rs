@@ -0,0 +1,107 @@
+// Known-good answers, used to check for regressions
+//
+// answers/YYYY/dayN.txt holds the answer to part 1 on the first line and part 2 on the second.
+// Multi-line answers are written on a single line with each newline escaped as "\n".
+// A missing file or empty line means the answer hasn't been recorded yet.
+//
+// The same format is used for the expected answers of example inputs (see examples.rs).
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Verdict {
+    Pass,
+    Fail,
+    New,
+}
+
+impl Verdict {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Verdict::Pass => "PASS",
+            Verdict::Fail => "FAIL",
+            Verdict::New => "NEW",
+        }
+    }
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct Answers {
+    parts: [Option<String>; 2],
+}
+
+impl Answers {
+    pub fn load(year: u32, day: u32) -> Self {
+        Self::load_file(&format!("answers/{}/day{}.txt", year, day))
+    }
+
+    pub fn load_file(filename: &str) -> Self {
+        match std::fs::read_to_string(filename) {
+            Ok(s) => Self::parse(&s),
+            Err(_) => Self::default(),
+        }
+    }
+
+    fn parse(s: &str) -> Self {
+        let mut answers = Self::default();
+        for (part, line) in s.lines().take(2).enumerate() {
+            if !line.trim().is_empty() {
+                answers.parts[part] = Some(line.replace("\\n", "\n"));
+            }
+        }
+        answers
+    }
+
+    // expected answer for part 1 or 2
+    pub fn get(&self, part: u8) -> Option<&str> {
+        self.parts[part as usize - 1].as_deref()
+    }
+
+    pub fn set(&mut self, part: u8, answer: &str) {
+        self.parts[part as usize - 1] = Some(answer.to_string());
+    }
+
+    pub fn save(&self, year: u32, day: u32) -> std::io::Result<()> {
+        std::fs::create_dir_all(format!("answers/{}", year))?;
+        std::fs::write(format!("answers/{}/day{}.txt", year, day), self.format())
+    }
+
+    fn format(&self) -> String {
+        self.parts
+            .iter()
+            .map(|part| part.as_deref().unwrap_or("").replace('\n', "\\n") + "\n")
+            .collect()
+    }
+
+    pub fn check(&self, part: u8, result: &str) -> Verdict {
+        match self.get(part) {
+            Some(expected) if normalise(expected) == normalise(result) => Verdict::Pass,
+            Some(_) => Verdict::Fail,
+            None => Verdict::New,
+        }
+    }
+}
+
+// trailing whitespace on each line of a multi-line answer isn't significant
+fn normalise(s: &str) -> Vec<&str> {
+    s.trim_end().lines().map(|line| line.trim_end()).collect()
+}
+
+#[test]
+fn test() {
+    let answers = Answers::parse("1234\n▛▀▖ \\n▌ ▌\n");
+    assert_eq!(Some("1234"), answers.get(1));
+    assert_eq!(Some("▛▀▖ \n▌ ▌"), answers.get(2));
+    assert_eq!(Verdict::Pass, answers.check(1, "1234"));
+    assert_eq!(Verdict::Fail, answers.check(1, "1235"));
+    assert_eq!(Verdict::Pass, answers.check(2, "▛▀▖\n▌ ▌\n"));
+
+    let answers = Answers::parse("1234\n");
+    assert_eq!(Verdict::Pass, answers.check(1, "1234"));
+    assert_eq!(Verdict::New, answers.check(2, "5678"));
+
+    assert_eq!(Answers::default(), Answers::parse(""));
+
+    let mut answers = Answers::default();
+    answers.set(2, "▛▀▖\n▌ ▌");
+    assert_eq!("\n▛▀▖\\n▌ ▌\n", answers.format());
+    assert_eq!(answers, Answers::parse(&answers.format()));
+}
@@ -0,0 +1,354 @@
+// Access to adventofcode.com: downloading puzzle inputs and submitting answers
+//
+// Requests are authenticated with the session cookie of a logged in browser, read from the
+// AOC_SESSION environment variable or ~/.config/adventofcode/session.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, FixedOffset, NaiveDate, TimeZone, Utc};
+use regex::Regex;
+
+use crate::http::{Http, Response};
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+// minimum time between requests, to go easy on the server
+const REQUEST_INTERVAL: Duration = Duration::from_secs(1);
+
+pub fn session_token() -> Result<String, String> {
+    if let Ok(token) = std::env::var("AOC_SESSION")
+        && !token.trim().is_empty()
+    {
+        return Ok(token.trim().to_string());
+    }
+    let path = config_dir().join("session");
+    match fs::read_to_string(&path) {
+        Ok(token) if !token.trim().is_empty() => Ok(token.trim().to_string()),
+        _ => Err(format!(
+            "No session token: set AOC_SESSION or write the session cookie to {}",
+            path.display()
+        )),
+    }
+}
+
+fn config_dir() -> PathBuf {
+    match std::env::var_os("XDG_CONFIG_HOME") {
+        Some(dir) => PathBuf::from(dir),
+        None => PathBuf::from(std::env::var_os("HOME").unwrap_or_default()).join(".config"),
+    }
+    .join("adventofcode")
+}
+
+// puzzles unlock at midnight US/Eastern, which is always EST (UTC-5) in December
+pub fn eastern_now() -> DateTime<FixedOffset> {
+    Utc::now().with_timezone(&FixedOffset::west_opt(5 * 3600).unwrap())
+}
+
+// from 2025 there are only 12 puzzles
+pub fn last_day(year: u32) -> u32 {
+    if year >= 2025 { 12 } else { 25 }
+}
+
+pub fn released(year: u32, day: u32, now: DateTime<FixedOffset>) -> bool {
+    if year < 2015 || day < 1 || day > last_day(year) {
+        return false;
+    }
+    let unlock = NaiveDate::from_ymd_opt(year as i32, 12, day)
+        .unwrap()
+        .and_hms_opt(0, 0, 0)
+        .unwrap();
+    now.offset().from_local_datetime(&unlock).unwrap() <= now
+}
+
+// every released (year, day), optionally restricted to a year or a single day
+pub fn released_days(
+    year: Option<u32>,
+    day: Option<u32>,
+    now: DateTime<FixedOffset>,
+) -> Vec<(u32, u32)> {
+    let years = match year {
+        Some(year) => year..=year,
+        None => 2015..=now.format("%Y").to_string().parse().unwrap(),
+    };
+    years
+        .flat_map(|year| (1..=last_day(year)).map(move |day| (year, day)))
+        .filter(|&(y, d)| day.is_none_or(|day| day == d) && released(y, d, now))
+        .collect()
+}
+
+pub fn input_path(dir: &Path, year: u32, day: u32) -> PathBuf {
+    dir.join(year.to_string()).join(format!("day{}.txt", day))
+}
+
+// The site's response to a submitted answer
+#[derive(Clone, Debug, PartialEq)]
+pub enum Reply {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong, // wrong, with no hint as to which way
+    Wait(Duration),
+    AlreadySolved, // part is already solved, or isn't unlocked yet
+    Unknown(String),
+}
+
+impl Reply {
+    // the reply page is a full HTML document; only the <article> text matters
+    pub fn parse(html: &str) -> Self {
+        let text = Regex::new(r"(?s)<article>(.*?)</article>")
+            .unwrap()
+            .captures(html)
+            .map_or(html, |c| c.get(1).unwrap().as_str());
+        let text = Regex::new(r"<[^>]*>").unwrap().replace_all(text, "");
+        if text.contains("That's the right answer") {
+            Reply::Correct
+        } else if text.contains("your answer is too high") {
+            Reply::TooHigh
+        } else if text.contains("your answer is too low") {
+            Reply::TooLow
+        } else if text.contains("That's not the right answer") {
+            Reply::Wrong
+        } else if let Some(c) = Regex::new(r"(?i)you have (?:(\d+)m )?(\d+)s left to wait")
+            .unwrap()
+            .captures(&text)
+        {
+            let mins: u64 = c.get(1).map_or(0, |m| m.as_str().parse().unwrap());
+            let secs: u64 = c[2].parse().unwrap();
+            Reply::Wait(Duration::from_secs(mins * 60 + secs))
+        } else if text.contains("You don't seem to be solving the right level") {
+            Reply::AlreadySolved
+        } else {
+            Reply::Unknown(text.trim().to_string())
+        }
+    }
+
+    // name used in the submissions ledger for replies that say something about the answer
+    pub fn label(&self) -> Option<&'static str> {
+        match self {
+            Reply::Correct => Some("correct"),
+            Reply::TooHigh => Some("too high"),
+            Reply::TooLow => Some("too low"),
+            Reply::Wrong => Some("wrong"),
+            _ => None,
+        }
+    }
+
+    pub fn from_label(label: &str) -> Option<Self> {
+        [Reply::Correct, Reply::TooHigh, Reply::TooLow, Reply::Wrong]
+            .into_iter()
+            .find(|reply| reply.label() == Some(label))
+    }
+}
+
+impl std::fmt::Display for Reply {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Reply::Wait(time) => write!(f, "answered too recently, wait {}s", time.as_secs()),
+            Reply::AlreadySolved => f.write_str("already solved (or not unlocked yet)"),
+            Reply::Unknown(text) => write!(f, "unrecognised response: {}", text),
+            _ => f.write_str(self.label().unwrap()),
+        }
+    }
+}
+
+pub struct Client<H: Http> {
+    http: H,
+    base_url: String,
+    session: String,
+    interval: Duration,
+    last_request: Option<Instant>,
+}
+
+impl<H: Http> Client<H> {
+    pub fn new(http: H, session: String) -> Self {
+        Self {
+            http,
+            base_url: BASE_URL.to_string(),
+            session,
+            interval: REQUEST_INTERVAL,
+            last_request: None,
+        }
+    }
+
+    // waits until at least interval has passed since the previous request
+    fn throttle(&mut self) {
+        if let Some(last) = self.last_request {
+            thread::sleep(self.interval.saturating_sub(last.elapsed()));
+        }
+        self.last_request = Some(Instant::now());
+    }
+
+    fn get(&mut self, path: &str) -> Result<Response, String> {
+        self.throttle();
+        let url = format!("{}{}", self.base_url, path);
+        self.http.get(&url, &format!("session={}", self.session))
+    }
+
+    pub fn input(&mut self, year: u32, day: u32) -> Result<String, String> {
+        let response = self.get(&format!("/{}/day/{}/input", year, day))?;
+        if response.status == 200 {
+            Ok(response.body)
+        } else {
+            Err(format!(
+                "HTTP {}: {}",
+                response.status,
+                response.body.trim()
+            ))
+        }
+    }
+
+    pub fn submit(&mut self, year: u32, day: u32, part: u8, answer: &str) -> Result<Reply, String> {
+        self.throttle();
+        let url = format!("{}/{}/day/{}/answer", self.base_url, year, day);
+        let level = part.to_string();
+        let response = self.http.post(
+            &url,
+            &format!("session={}", self.session),
+            &[("level", &level), ("answer", answer)],
+        )?;
+        if response.status == 200 {
+            Ok(Reply::parse(&response.body))
+        } else {
+            Err(format!(
+                "HTTP {}: {}",
+                response.status,
+                response.body.trim()
+            ))
+        }
+    }
+}
+
+// Downloads the input of each day that isn't already in dir, returning how many were fetched
+pub fn fetch<H: Http>(
+    client: &mut Client<H>,
+    dir: &Path,
+    days: &[(u32, u32)],
+) -> Result<usize, String> {
+    let mut count = 0;
+    for &(year, day) in days {
+        let path = input_path(dir, year, day);
+        if path.exists() {
+            continue;
+        }
+        println!("{} day {:02}: Downloading {}", year, day, path.display());
+        let input = client
+            .input(year, day)
+            .map_err(|e| format!("{} day {:02}: {}", year, day, e))?;
+        fs::create_dir_all(path.parent().unwrap()).map_err(|e| e.to_string())?;
+        fs::write(&path, input).map_err(|e| format!("{}: {}", path.display(), e))?;
+        count += 1;
+    }
+    Ok(count)
+}
+
+#[test]
+fn test() {
+    let est = FixedOffset::west_opt(5 * 3600).unwrap();
+    let now = est.with_ymd_and_hms(2025, 12, 3, 0, 0, 0).unwrap();
+    assert!(released(2025, 3, now));
+    assert!(!released(2025, 4, now));
+    assert!(released(2024, 25, now));
+    assert!(!released(2025, 13, now));
+    // 23:59 on the 3rd in UTC-5 is already the 4th in UTC
+    let now = est.with_ymd_and_hms(2025, 12, 3, 23, 59, 0).unwrap();
+    assert!(!released(2025, 4, now));
+
+    assert_eq!(253, released_days(None, None, now).len());
+    assert_eq!(
+        vec![(2025, 1), (2025, 2), (2025, 3)],
+        released_days(Some(2025), None, now)
+    );
+    assert_eq!(vec![(2019, 7)], released_days(Some(2019), Some(7), now));
+
+    let (url, server) =
+        crate::http::stub_server(vec![(200, "1\n2\n"), (200, "3\n4\n"), (404, "Not Found")]);
+    let mut client = Client::new(crate::http::Curl, "abc".to_string());
+    client.base_url = url;
+    client.interval = Duration::from_millis(100);
+    let dir = std::env::temp_dir().join(format!("aoc-fetch-{}", std::process::id()));
+    fs::create_dir_all(dir.join("2021")).unwrap();
+    fs::write(input_path(&dir, 2021, 2), "cached").unwrap();
+
+    let t0 = Instant::now();
+    let days = [(2021, 1), (2021, 2), (2021, 3)];
+    assert_eq!(Ok(2), fetch(&mut client, &dir, &days));
+    assert!(t0.elapsed() >= Duration::from_millis(100));
+    assert_eq!(
+        "1\n2\n",
+        fs::read_to_string(input_path(&dir, 2021, 1)).unwrap()
+    );
+    assert_eq!(
+        "cached",
+        fs::read_to_string(input_path(&dir, 2021, 2)).unwrap()
+    );
+    assert_eq!(
+        "3\n4\n",
+        fs::read_to_string(input_path(&dir, 2021, 3)).unwrap()
+    );
+
+    fs::remove_file(input_path(&dir, 2021, 3)).unwrap();
+    assert_eq!(
+        Err("2021 day 03: HTTP 404: Not Found".to_string()),
+        fetch(&mut client, &dir, &[(2021, 3)])
+    );
+    assert!(!input_path(&dir, 2021, 3).exists());
+    fs::remove_dir_all(&dir).unwrap();
+
+    let requests = server.join().unwrap();
+    assert!(requests[1].starts_with("GET /2021/day/3/input "));
+
+    let page = |text| {
+        format!(
+            "<html><main><article><p>{}</p></article></main></html>",
+            text
+        )
+    };
+    assert_eq!(
+        Reply::Correct,
+        Reply::parse(&page(
+            "That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer."
+        ))
+    );
+    assert_eq!(
+        Reply::TooHigh,
+        Reply::parse(&page(
+            "That's not the right answer; your answer is too high.  If you're stuck, ..."
+        ))
+    );
+    assert_eq!(
+        Reply::TooLow,
+        Reply::parse(&page(
+            "That's not the right answer; your answer is too low."
+        ))
+    );
+    assert_eq!(
+        Reply::Wrong,
+        Reply::parse(&page("That's not the right answer.  If you're stuck, ..."))
+    );
+    assert_eq!(
+        Reply::Wait(Duration::from_secs(83)),
+        Reply::parse(&page(
+            "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 23s left to wait."
+        ))
+    );
+    assert_eq!(
+        Reply::Wait(Duration::from_secs(34)),
+        Reply::parse(&page(
+            "You gave an answer too recently; you have 34s left to wait."
+        ))
+    );
+    assert_eq!(
+        Reply::AlreadySolved,
+        Reply::parse(&page(
+            "You don't seem to be solving the right level.  Did you already complete it?"
+        ))
+    );
+    assert_eq!(
+        Reply::Unknown("Huh?".to_string()),
+        Reply::parse(&page("Huh?"))
+    );
+    assert_eq!(Some(Reply::TooLow), Reply::from_label("too low"));
+}
@@ -0,0 +1,72 @@
+use std::fmt;
+use std::time::Duration;
+
+// Summary of repeated timings of one phase (parse, part 1 or part 2)
+#[derive(Debug, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn new(times: &[Duration]) -> Self {
+        if times.is_empty() {
+            panic!("no timings to summarise");
+        }
+        let mut sorted = times.to_vec();
+        sorted.sort();
+        let n = sorted.len();
+        let median = if n.is_multiple_of(2) {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        } else {
+            sorted[n / 2]
+        };
+        let mean = sorted.iter().sum::<Duration>() / n as u32;
+        let variance = sorted
+            .iter()
+            .map(|t| (t.as_secs_f64() - mean.as_secs_f64()).powi(2))
+            .sum::<f64>()
+            / n as f64;
+        Self {
+            min: sorted[0],
+            median,
+            mean,
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+// min/median/mean/stddev in milliseconds, to line up with header()
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for t in [self.min, self.median, self.mean, self.stddev] {
+            write!(f, " {:10.3}ms", t.as_secs_f64() * 1000.0)?;
+        }
+        Ok(())
+    }
+}
+
+pub fn header() -> String {
+    ["min", "median", "mean", "stddev"]
+        .iter()
+        .map(|label| format!(" {:>12}", label))
+        .collect()
+}
+
+#[test]
+fn test() {
+    let ms = Duration::from_millis;
+    assert_eq!(
+        Stats {
+            min: ms(2),
+            median: Duration::from_micros(4500),
+            mean: ms(5),
+            stddev: ms(2),
+        },
+        Stats::new(&[ms(4), ms(2), ms(4), ms(4), ms(5), ms(5), ms(7), ms(9)])
+    );
+    assert_eq!(ms(3), Stats::new(&[ms(3)]).median);
+    assert_eq!(ms(3), Stats::new(&[ms(5), ms(1), ms(3)]).median);
+}
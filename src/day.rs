@@ -0,0 +1,116 @@
+use std::fmt;
+
+// A day's puzzle: input is parsed once, then each part is solved from the parsed input
+pub trait Day {
+    type Input<'a>;
+
+    fn parse_input(input: &str) -> Self::Input<'_>;
+    fn part1(input: &Self::Input<'_>) -> Answer;
+    fn part2(input: &Self::Input<'_>) -> Answer;
+}
+
+// Implements Day for a `Solution` struct in the calling module, using that module's
+// parse_input, part1 and part2 functions.  Argument is the type returned by parse_input;
+// if it borrows from the input, use the lifetime 'a (eg `&'a str`).
+//
+// The parts may return anything that converts into an Answer.
+macro_rules! impl_day {
+    ($input:ty) => {
+        pub struct Solution;
+
+        impl $crate::day::Day for Solution {
+            type Input<'a> = $input;
+
+            fn parse_input(input: &str) -> Self::Input<'_> {
+                parse_input(input)
+            }
+
+            fn part1(input: &Self::Input<'_>) -> $crate::day::Answer {
+                part1(input).into()
+            }
+
+            fn part2(input: &Self::Input<'_>) -> $crate::day::Answer {
+                part2(input).into()
+            }
+        }
+    };
+}
+
+pub(crate) use impl_day;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Answer {
+    Int(i128),
+    Text(String),
+    // letters drawn in pixels that OCR couldn't read: the picture, and why it couldn't
+    Art(String, String),
+    // puzzle has no answer for this part (eg day 25 part 2)
+    NotApplicable,
+    Unimplemented,
+}
+
+impl Answer {
+    // true if this is an answer to the puzzle (rather than a placeholder)
+    pub fn is_answer(&self) -> bool {
+        !matches!(self, Answer::NotApplicable | Answer::Unimplemented)
+    }
+
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Answer::Int(_) => "int",
+            Answer::Text(_) => "text",
+            Answer::Art(..) => "art",
+            Answer::NotApplicable => "n/a",
+            Answer::Unimplemented => "unimplemented",
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{}", n),
+            Answer::Text(s) | Answer::Art(s, _) => f.write_str(s),
+            Answer::NotApplicable => f.write_str("n/a"),
+            Answer::Unimplemented => f.write_str("unimplemented"),
+        }
+    }
+}
+
+macro_rules! from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Answer::Int(n as i128)
+                }
+            }
+        )*
+    };
+}
+
+from_int!(u8, u16, u32, u64, usize, i8, i16, i32, i64, i128);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
+
+#[test]
+fn test() {
+    assert_eq!(Answer::Int(42), 42u8.into());
+    assert_eq!(Answer::Int(-42), (-42i64).into());
+    assert_eq!(Answer::Int(u64::MAX as i128), u64::MAX.into());
+    assert_eq!("18446744073709551615", Answer::from(u64::MAX).to_string());
+    assert_eq!(Answer::Text("abc".to_string()), "abc".into());
+    assert_eq!("n/a", Answer::NotApplicable.to_string());
+    assert!(Answer::Art("#\n#".to_string(), "no font is 2 pixels high".to_string()).is_answer());
+    assert!(!Answer::Unimplemented.is_answer());
+}
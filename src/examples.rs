@@ -0,0 +1,133 @@
+// Example inputs from the puzzle text, with their expected answers
+//
+// examples/YYYY/dayN/NAME.txt is the input and examples/YYYY/dayN/NAME.answers holds the
+// expected answers in the same format as answers/YYYY/dayN.txt (leave a line empty if the
+// example doesn't apply to that part).
+//
+// build.rs generates a test for each example, and `cargo run YYYY N --examples` runs them
+// through the runner.
+//
+// Alternative puzzle inputs in input/YYYY/dayN/ use the same layout (see --all-inputs).
+
+use std::fs;
+
+use crate::answers::Answers;
+
+pub struct Example {
+    pub name: String,
+    pub input: String,
+    pub answers: Answers,
+}
+
+fn dir(year: u32, day: u32) -> String {
+    format!("examples/{}/day{}", year, day)
+}
+
+// all examples for a day, sorted by name
+pub fn find(year: u32, day: u32) -> Vec<Example> {
+    find_in(&dir(year, day))
+}
+
+// every NAME.txt (with its NAME.answers) in dir, sorted by name
+pub fn find_in(dir: &str) -> Vec<Example> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return vec![];
+    };
+    let mut names: Vec<String> = entries
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            if path.extension()? == "txt" {
+                Some(path.file_stem()?.to_str()?.to_string())
+            } else {
+                None
+            }
+        })
+        .collect();
+    names.sort();
+    names
+        .into_iter()
+        .map(|name| load_from(dir, &name))
+        .collect()
+}
+
+pub fn load(year: u32, day: u32, name: &str) -> Example {
+    load_from(&dir(year, day), name)
+}
+
+fn load_from(dir: &str, name: &str) -> Example {
+    let base = format!("{}/{}", dir, name);
+    Example {
+        name: name.to_string(),
+        input: fs::read_to_string(format!("{}.txt", base))
+            .unwrap_or_else(|e| panic!("{}.txt: {}", base, e)),
+        answers: Answers::load_file(&format!("{}.answers", base)),
+    }
+}
+
+// Called by the tests generated by build.rs
+#[cfg(test)]
+pub fn test<D: crate::day::Day>(year: u32, day: u32, name: &str) {
+    check::<D>(year, day, &load(year, day, name));
+}
+
+#[cfg(test)]
+fn check<D: crate::day::Day>(year: u32, day: u32, example: &Example) {
+    use crate::answers::Verdict;
+
+    // a new day's empty example has nothing to check, and its input may not parse
+    if example.answers.get(1).is_none() && example.answers.get(2).is_none() {
+        return;
+    }
+    let name = &example.name;
+    let input = D::parse_input(&example.input);
+    for part in 1..=2 {
+        if let Some(expected) = example.answers.get(part) {
+            let answer = if part == 1 {
+                D::part1(&input)
+            } else {
+                D::part2(&input)
+            };
+            assert_eq!(
+                Verdict::Pass,
+                example.answers.check(part, &answer.to_string()),
+                "{} day {} example {} part {}: expected {}, got {}",
+                year,
+                day,
+                name,
+                part,
+                expected,
+                answer
+            );
+        }
+    }
+}
+
+#[test]
+fn test_unanswered() {
+    use crate::day::{Answer, Day};
+
+    struct Unparseable;
+
+    impl Day for Unparseable {
+        type Input<'a> = ();
+
+        fn parse_input(_input: &str) {
+            panic!("parse_input called");
+        }
+
+        fn part1(_input: &()) -> Answer {
+            Answer::Unimplemented
+        }
+
+        fn part2(_input: &()) -> Answer {
+            Answer::Unimplemented
+        }
+    }
+
+    let example = Example {
+        name: "example".to_string(),
+        input: String::new(),
+        answers: Answers::default(),
+    };
+    check::<Unparseable>(2025, 1, &example);
+}
@@ -63,7 +63,6 @@ impl Sub for Pos {
 }
 
 // Compass directions
-#[allow(dead_code)]
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum Compass {
     North,
@@ -88,6 +87,10 @@ pub const ALL_DIRS: [Compass; 8] = [
 ];
 
 impl Compass {
+    // the 4 directions in reading order (top to bottom, then left to right of their targets)
+    pub const CARDINALS: [Compass; 4] =
+        [Compass::North, Compass::West, Compass::East, Compass::South];
+
     pub fn left90(&self) -> Self {
         match self {
             Compass::North => Compass::West,
@@ -209,7 +212,6 @@ impl<T> Grid<T>
 where
     T: Clone,
 {
-    #[allow(dead_code)]
     pub fn new(width: u32, height: u32, default: T) -> Self {
         Self {
             maxx: width as i32 - 1,
@@ -266,22 +268,22 @@ where
         }
     }
 
-    #[allow(dead_code)]
     pub fn width(&self) -> u32 {
         (self.maxx + 1).try_into().unwrap()
     }
 
-    #[allow(dead_code)]
     pub fn height(&self) -> u32 {
         (self.maxy + 1).try_into().unwrap()
     }
 
-    #[allow(dead_code)]
     pub fn len(&self) -> usize {
         self.data.len()
     }
 
-    #[allow(dead_code)]
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
     pub fn minx(&self) -> i32 {
         0
     }
@@ -290,18 +292,15 @@ where
         self.maxx
     }
 
-    #[allow(dead_code)]
     pub fn miny(&self) -> i32 {
         0
     }
 
-    #[allow(dead_code)]
     pub fn maxy(&self) -> i32 {
         self.maxy
     }
 
     // get neighbouring position (None if it would move off grid)
-    #[allow(dead_code)]
     pub fn bounded_pos(&self, p: Pos, dir: Compass) -> Option<Pos> {
         let p2 = p.step(dir);
         if p2.x < 0 || p2.x > self.maxx || p2.y < 0 || p2.y > self.maxy {
@@ -331,6 +330,147 @@ where
         self.get(p.step(dir))
     }
 
+    // neighbouring (position, direction, value) in the 4 cardinal directions (reading order)
+    // or all 8 directions (clockwise from north).  Positions off the grid have the default
+    // value, like look().
+    pub fn neighbours4(&self, p: Pos) -> impl Iterator<Item = (Pos, Compass, &T)> {
+        self.neighbours(&Compass::CARDINALS, move |dir| Some(p.step(dir)))
+    }
+
+    pub fn neighbours8(&self, p: Pos) -> impl Iterator<Item = (Pos, Compass, &T)> {
+        self.neighbours(&ALL_DIRS, move |dir| Some(p.step(dir)))
+    }
+
+    // as above, skipping positions that would be off the grid
+    pub fn bounded_neighbours4(&self, p: Pos) -> impl Iterator<Item = (Pos, Compass, &T)> {
+        self.neighbours(&Compass::CARDINALS, move |dir| self.bounded_pos(p, dir))
+    }
+
+    pub fn bounded_neighbours8(&self, p: Pos) -> impl Iterator<Item = (Pos, Compass, &T)> {
+        self.neighbours(&ALL_DIRS, move |dir| self.bounded_pos(p, dir))
+    }
+
+    // as above, wrapping around the edges of the grid
+    pub fn wrapped_neighbours4(&self, p: Pos) -> impl Iterator<Item = (Pos, Compass, &T)> {
+        self.neighbours(&Compass::CARDINALS, move |dir| {
+            Some(self.wrapped_pos(p, dir))
+        })
+    }
+
+    pub fn wrapped_neighbours8(&self, p: Pos) -> impl Iterator<Item = (Pos, Compass, &T)> {
+        self.neighbours(&ALL_DIRS, move |dir| Some(self.wrapped_pos(p, dir)))
+    }
+
+    // copies of the grid turned clockwise, flipped or transposed (swapping x and y)
+    pub fn rotate90(&self) -> Self {
+        let maxy = self.maxy;
+        self.remap(self.height(), self.width(), |p| Pos {
+            x: p.y,
+            y: maxy - p.x,
+        })
+    }
+
+    pub fn rotate180(&self) -> Self {
+        let (maxx, maxy) = (self.maxx, self.maxy);
+        self.remap(self.width(), self.height(), |p| Pos {
+            x: maxx - p.x,
+            y: maxy - p.y,
+        })
+    }
+
+    pub fn rotate270(&self) -> Self {
+        let maxx = self.maxx;
+        self.remap(self.height(), self.width(), |p| Pos {
+            x: maxx - p.y,
+            y: p.x,
+        })
+    }
+
+    // mirrored left to right
+    pub fn flip_horizontal(&self) -> Self {
+        let maxx = self.maxx;
+        self.remap(self.width(), self.height(), |p| Pos {
+            x: maxx - p.x,
+            y: p.y,
+        })
+    }
+
+    // mirrored top to bottom
+    pub fn flip_vertical(&self) -> Self {
+        let maxy = self.maxy;
+        self.remap(self.width(), self.height(), |p| Pos {
+            x: p.x,
+            y: maxy - p.y,
+        })
+    }
+
+    pub fn transpose(&self) -> Self {
+        self.remap(self.height(), self.width(), |p| Pos { x: p.y, y: p.x })
+    }
+
+    // the rectangle from nw to se (inclusive) as a new grid.  Any part of it outside this grid
+    // gets the default value.  Panics if se is above or left of nw.
+    pub fn crop(&self, nw: Pos, se: Pos) -> Self {
+        assert!(
+            nw.x <= se.x && nw.y <= se.y,
+            "crop: {} is not above and left of {}",
+            nw,
+            se
+        );
+        self.remap(
+            (se.x - nw.x + 1).try_into().unwrap(),
+            (se.y - nw.y + 1).try_into().unwrap(),
+            |p| p + nw,
+        )
+    }
+
+    // a grid of across x down copies of this one.  Each cell is f(tile x, tile y, value),
+    // where tile (0, 0) is the top-left copy.
+    pub fn tile<F>(&self, across: u32, down: u32, f: F) -> Self
+    where
+        F: Fn(u32, u32, &T) -> T,
+    {
+        let (width, height) = (self.width(), self.height());
+        let mut data = Vec::with_capacity(self.len() * (across * down) as usize);
+        for y in 0..height * down {
+            for x in 0..width * across {
+                let p = Pos::from(((x % width) as usize, (y % height) as usize));
+                data.push(f(x / width, y / height, self.get(p)));
+            }
+        }
+        Grid {
+            maxx: (width * across) as i32 - 1,
+            maxy: (height * down) as i32 - 1,
+            default: self.default.clone(),
+            data,
+        }
+    }
+
+    // a new width x height grid, with each cell copied from this grid at from(p)
+    fn remap(&self, width: u32, height: u32, from: impl Fn(Pos) -> Pos) -> Self {
+        let mut data = Vec::with_capacity((width * height) as usize);
+        for y in 0..height as i32 {
+            for x in 0..width as i32 {
+                data.push(self.get(from(Pos { x, y })).clone());
+            }
+        }
+        Grid {
+            maxx: width as i32 - 1,
+            maxy: height as i32 - 1,
+            default: self.default.clone(),
+            data,
+        }
+    }
+
+    fn neighbours<'a>(
+        &'a self,
+        dirs: &'static [Compass],
+        step: impl Fn(Compass) -> Option<Pos> + 'a,
+    ) -> impl Iterator<Item = (Pos, Compass, &'a T)> {
+        dirs.iter()
+            .filter_map(move |&dir| step(dir).map(|p| (p, dir, self.get(p))))
+    }
+
     fn index(&self, p: Pos) -> Option<usize> {
         if p.x < 0 || p.x > self.maxx || p.y < 0 || p.y > self.maxy {
             None
@@ -442,13 +582,16 @@ where
         self.data.len().try_into().unwrap()
     }
 
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
     // min/max functions return 0 if the grid is empty (not accurate but simpler)
 
     pub fn minx(&self) -> i32 {
         self.nw.x
     }
 
-    #[allow(dead_code)]
     pub fn maxx(&self) -> i32 {
         self.se.x
     }
@@ -457,13 +600,85 @@ where
         self.nw.y
     }
 
-    #[allow(dead_code)]
     pub fn maxy(&self) -> i32 {
         self.se.y
     }
 
-    #[allow(dead_code)]
     pub fn look(&self, p: Pos, dir: Compass) -> &T {
         self.get(p.step(dir))
     }
+
+    // neighbouring (position, direction, value), as for Grid
+    pub fn neighbours4(&self, p: Pos) -> impl Iterator<Item = (Pos, Compass, &T)> {
+        Compass::CARDINALS
+            .iter()
+            .map(move |&dir| (p.step(dir), dir, self.get(p.step(dir))))
+    }
+
+    pub fn neighbours8(&self, p: Pos) -> impl Iterator<Item = (Pos, Compass, &T)> {
+        ALL_DIRS
+            .iter()
+            .map(move |&dir| (p.step(dir), dir, self.get(p.step(dir))))
+    }
+}
+
+#[test]
+fn test() {
+    use Compass::*;
+
+    let grid = Grid::from_input("abc\ndef\n", '.', |c| c);
+    let values = |it: &mut dyn Iterator<Item = (Pos, Compass, &char)>| -> String {
+        it.map(|(_, _, c)| *c).collect()
+    };
+    assert_eq!("..bd", values(&mut grid.neighbours4(ORIGIN)));
+    assert_eq!("bd", values(&mut grid.bounded_neighbours4(ORIGIN)));
+    assert_eq!("dcbd", values(&mut grid.wrapped_neighbours4(ORIGIN)));
+    let middle = Pos { x: 1, y: 0 };
+    assert_eq!("..cfeda.", values(&mut grid.neighbours8(middle)));
+    assert_eq!("cfeda", values(&mut grid.bounded_neighbours8(middle)));
+    assert_eq!(
+        vec![(Pos { x: 2, y: 0 }, North), (Pos { x: 1, y: 1 }, West)],
+        grid.bounded_neighbours4(Pos { x: 2, y: 1 })
+            .map(|(p, dir, _)| (p, dir))
+            .collect::<Vec<_>>()
+    );
+
+    let text = |grid: &Grid<char>| -> String {
+        grid.iter()
+            .map(|(p, c)| {
+                if p.x == grid.maxx() {
+                    format!("{}\n", c)
+                } else {
+                    c.to_string()
+                }
+            })
+            .collect()
+    };
+    assert_eq!("da\neb\nfc\n", text(&grid.rotate90()));
+    assert_eq!("fed\ncba\n", text(&grid.rotate180()));
+    assert_eq!("cf\nbe\nad\n", text(&grid.rotate270()));
+    assert_eq!("cba\nfed\n", text(&grid.flip_horizontal()));
+    assert_eq!("def\nabc\n", text(&grid.flip_vertical()));
+    assert_eq!("ad\nbe\ncf\n", text(&grid.transpose()));
+    assert_eq!("bc.\nef.\n", text(&grid.crop(middle, Pos { x: 3, y: 1 })));
+    assert_eq!("b\n", text(&grid.crop(middle, middle)));
+    assert_eq!(
+        "abcABC\ndefDEF\nABCabc\nDEFdef\n",
+        text(&grid.tile(2, 2, |x, y, c| if (x + y) % 2 == 1 {
+            c.to_ascii_uppercase()
+        } else {
+            *c
+        }))
+    );
+
+    let mut sparse = SparseGrid::new('.');
+    sparse.set(Pos { x: 0, y: -1 }, 'x');
+    assert_eq!("x...", values(&mut sparse.neighbours4(ORIGIN)));
+}
+
+#[test]
+#[should_panic(expected = "crop: (1,1) is not above and left of (2,0)")]
+fn test_crop_reversed() {
+    let grid = Grid::from_input("abc\ndef\n", '.', |c| c);
+    grid.crop(Pos { x: 1, y: 1 }, Pos { x: 2, y: 0 });
 }
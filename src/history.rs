@@ -0,0 +1,256 @@
+// History of solver timings, used to spot performance regressions
+//
+// Each run of a day against its real input appends a JSON record per part to timings.jsonl.
+// Timings are only compared against runs from the same build profile (debug or release).
+
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::process::Command;
+use std::time::Duration;
+
+use serde_json::{Value, json};
+
+pub const FILENAME: &str = "timings.jsonl";
+
+// times and peak memory under these aren't compared as they're mostly noise
+const MIN_TIME: Duration = Duration::from_millis(1);
+const MIN_MEMORY: usize = 1 << 20;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Entry {
+    pub year: u32,
+    pub day: u32,
+    pub part: u8,
+    pub commit: Option<String>,
+    pub profile: String,
+    pub timestamp: String,
+    pub parse_time: Duration,
+    pub solve_time: Duration,
+    pub peak_memory: Option<usize>, // only recorded when built with memory tracking
+}
+
+impl Entry {
+    pub fn new(
+        year: u32,
+        day: u32,
+        part: u8,
+        parse_time: Duration,
+        solve_time: Duration,
+        peak_memory: Option<usize>,
+    ) -> Self {
+        Self {
+            year,
+            day,
+            part,
+            commit: None,
+            profile: profile().to_string(),
+            timestamp: chrono::Local::now().to_rfc3339(),
+            parse_time,
+            solve_time,
+            peak_memory,
+        }
+    }
+
+    fn to_json(&self) -> Value {
+        json!({
+            "year": self.year,
+            "day": self.day,
+            "part": self.part,
+            "commit": self.commit,
+            "profile": self.profile,
+            "timestamp": self.timestamp,
+            "parse_time": self.parse_time.as_secs_f64(),
+            "solve_time": self.solve_time.as_secs_f64(),
+            "peak_memory": self.peak_memory,
+        })
+    }
+
+    fn from_json(v: &Value) -> Option<Self> {
+        Some(Self {
+            year: v["year"].as_u64()? as u32,
+            day: v["day"].as_u64()? as u32,
+            part: v["part"].as_u64()? as u8,
+            commit: v["commit"].as_str().map(String::from),
+            profile: v["profile"].as_str()?.to_string(),
+            timestamp: v["timestamp"].as_str()?.to_string(),
+            parse_time: Duration::from_secs_f64(v["parse_time"].as_f64()?),
+            solve_time: Duration::from_secs_f64(v["solve_time"].as_f64()?),
+            peak_memory: v["peak_memory"].as_u64().map(|n| n as usize),
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Baseline {
+    Previous,
+    Best,
+}
+
+impl TryFrom<&str> for Baseline {
+    type Error = String;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        match s {
+            "previous" => Ok(Baseline::Previous),
+            "best" => Ok(Baseline::Best),
+            _ => Err(format!(
+                "unknown baseline: {} (expected previous or best)",
+                s
+            )),
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct History {
+    entries: Vec<Entry>,
+}
+
+impl History {
+    pub fn load() -> Self {
+        Self::parse(&fs::read_to_string(FILENAME).unwrap_or_default())
+    }
+
+    fn parse(s: &str) -> Self {
+        Self {
+            entries: s
+                .lines()
+                .filter_map(|line| Entry::from_json(&serde_json::from_str(line).ok()?))
+                .collect(),
+        }
+    }
+
+    // appends entries to the history file, tagged with the current git commit
+    pub fn append(entries: &[Entry]) -> std::io::Result<()> {
+        if entries.is_empty() {
+            return Ok(());
+        }
+        let commit = git_commit();
+        let mut f = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(FILENAME)?;
+        for entry in entries {
+            let entry = Entry {
+                commit: commit.clone(),
+                ..entry.clone()
+            };
+            writeln!(f, "{}", entry.to_json())?;
+        }
+        Ok(())
+    }
+
+    // previous or best solve time of a part from an earlier run with the same build profile
+    pub fn baseline(&self, baseline: Baseline, year: u32, day: u32, part: u8) -> Option<Duration> {
+        pick(baseline, self.runs(year, day, part).map(|e| e.solve_time))
+    }
+
+    // previous or best peak memory of a part, from runs that measured it
+    pub fn baseline_memory(
+        &self,
+        baseline: Baseline,
+        year: u32,
+        day: u32,
+        part: u8,
+    ) -> Option<usize> {
+        pick(
+            baseline,
+            self.runs(year, day, part).filter_map(|e| e.peak_memory),
+        )
+    }
+
+    fn runs(&self, year: u32, day: u32, part: u8) -> impl DoubleEndedIterator<Item = &Entry> {
+        self.entries.iter().filter(move |e| {
+            e.year == year && e.day == day && e.part == part && e.profile == profile()
+        })
+    }
+}
+
+// percentage change from baseline to time, if it's more than threshold percent slower
+pub fn slowdown(baseline: Duration, time: Duration, threshold: f64) -> Option<f64> {
+    if time < MIN_TIME {
+        return None;
+    }
+    let change = (time.as_secs_f64() / baseline.as_secs_f64() - 1.0) * 100.0;
+    (change > threshold).then_some(change)
+}
+
+fn pick<T: Ord>(baseline: Baseline, mut values: impl DoubleEndedIterator<Item = T>) -> Option<T> {
+    match baseline {
+        Baseline::Previous => values.next_back(),
+        Baseline::Best => values.min(),
+    }
+}
+
+// percentage change from baseline to peak memory, if it's more than threshold percent bigger
+pub fn growth(baseline: usize, peak: usize, threshold: f64) -> Option<f64> {
+    if peak < MIN_MEMORY {
+        return None;
+    }
+    let change = (peak as f64 / baseline.max(1) as f64 - 1.0) * 100.0;
+    (change > threshold).then_some(change)
+}
+
+fn profile() -> &'static str {
+    if cfg!(debug_assertions) {
+        "debug"
+    } else {
+        "release"
+    }
+}
+
+// short hash of HEAD (with a + suffix if there are uncommitted changes), if in a git repo
+fn git_commit() -> Option<String> {
+    let output = Command::new("git")
+        .args(["describe", "--always", "--dirty=+", "--abbrev=10"])
+        .output()
+        .ok()?;
+    if output.status.success() {
+        Some(String::from_utf8(output.stdout).ok()?.trim().to_string())
+    } else {
+        None
+    }
+}
+
+#[test]
+fn test() {
+    let ms = Duration::from_millis;
+    let mut entries = vec![
+        Entry::new(2019, 18, 1, ms(5), ms(100), None),
+        Entry::new(2019, 18, 1, ms(5), ms(80), Some(3 << 20)),
+        Entry::new(2019, 18, 1, ms(5), ms(90), Some(2 << 20)),
+        Entry::new(2019, 18, 2, ms(5), ms(300), None),
+    ];
+    entries[0].commit = Some("abc123".to_string());
+    let history = History::parse(
+        &entries
+            .iter()
+            .map(|e| e.to_json().to_string() + "\n")
+            .collect::<String>(),
+    );
+    assert_eq!(entries, history.entries);
+    assert_eq!(
+        Some(ms(90)),
+        history.baseline(Baseline::Previous, 2019, 18, 1)
+    );
+    assert_eq!(Some(ms(80)), history.baseline(Baseline::Best, 2019, 18, 1));
+    assert_eq!(Some(ms(300)), history.baseline(Baseline::Best, 2019, 18, 2));
+    assert_eq!(None, history.baseline(Baseline::Best, 2019, 17, 1));
+    assert_eq!(
+        Some(2 << 20),
+        history.baseline_memory(Baseline::Best, 2019, 18, 1)
+    );
+    assert_eq!(
+        None,
+        history.baseline_memory(Baseline::Previous, 2019, 18, 2)
+    );
+    assert_eq!(Some(50.0), growth(2 << 20, 3 << 20, 10.0));
+    assert_eq!(None, growth(1000, 100_000, 10.0));
+
+    assert_eq!(None, slowdown(ms(100), ms(105), 10.0));
+    assert_eq!(Some(50.0), slowdown(ms(100), ms(150), 10.0).map(f64::round));
+    assert_eq!(
+        None,
+        slowdown(Duration::from_micros(100), Duration::from_micros(900), 10.0)
+    );
+}
@@ -0,0 +1,165 @@
+// Minimal HTTP client, used to talk to adventofcode.com
+//
+// Requests go through the Http trait so that they can be pointed at a stub server in tests.
+// The real implementation shells out to curl, which avoids pulling in a TLS stack.
+
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+// AoC asks automated tools to identify themselves
+const USER_AGENT: &str = "github.com/timocp/adventofcode";
+
+#[derive(Debug, PartialEq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+pub trait Http {
+    // GET url, sending cookie (eg "session=...") with the request
+    fn get(&mut self, url: &str, cookie: &str) -> Result<Response, String>;
+    // POST form fields to url, sending cookie with the request
+    fn post(&mut self, url: &str, cookie: &str, form: &[(&str, &str)]) -> Result<Response, String>;
+}
+
+pub struct Curl;
+
+impl Http for Curl {
+    fn get(&mut self, url: &str, cookie: &str) -> Result<Response, String> {
+        curl(&[url], cookie)
+    }
+
+    fn post(&mut self, url: &str, cookie: &str, form: &[(&str, &str)]) -> Result<Response, String> {
+        let fields: Vec<_> = form
+            .iter()
+            .map(|(name, value)| format!("{}={}", name, value))
+            .collect();
+        let mut args = vec![];
+        for field in &fields {
+            args.extend(["--data-urlencode", field]);
+        }
+        args.push(url);
+        curl(&args, cookie)
+    }
+}
+
+// Runs curl with args, appending the status code to the output so it can be split off.
+// The cookie is passed on stdin so that it doesn't show up in the process list.
+fn curl(args: &[&str], cookie: &str) -> Result<Response, String> {
+    let mut child = Command::new("curl")
+        .args(["-sS", "-A", USER_AGENT, "-H", "@-", "-w", "\n%{http_code}"])
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("curl: {}", e))?;
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(format!("Cookie: {}\n", cookie).as_bytes())
+        .map_err(|e| format!("curl: {}", e))?;
+    let output = child
+        .wait_with_output()
+        .map_err(|e| format!("curl: {}", e))?;
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+    }
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let (body, status) = stdout
+        .rsplit_once('\n')
+        .ok_or_else(|| "curl: no status code in output".to_string())?;
+    Ok(Response {
+        status: status
+            .parse()
+            .map_err(|_| format!("curl: bad status: {}", status))?,
+        body: body.to_string(),
+    })
+}
+
+// Serves each response in turn on a local port, returning the base URL and a handle that
+// yields the requests received
+#[cfg(test)]
+pub fn stub_server(
+    responses: Vec<(u16, &'static str)>,
+) -> (String, std::thread::JoinHandle<Vec<String>>) {
+    use std::io::{BufRead, BufReader, Read};
+    use std::net::TcpListener;
+
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let handle = std::thread::spawn(move || {
+        let mut requests = vec![];
+        for (status, body) in responses {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut request = String::new();
+            let mut content_length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some(len) = line.to_lowercase().strip_prefix("content-length:") {
+                    content_length = len.trim().parse().unwrap();
+                }
+                if line.trim_end().is_empty() {
+                    break;
+                }
+                request += &line;
+            }
+            let mut content = vec![0; content_length];
+            reader.read_exact(&mut content).unwrap();
+            request += "\r\n";
+            request += &String::from_utf8(content).unwrap();
+            write!(
+                reader.get_mut(),
+                "HTTP/1.1 {} X\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            )
+            .unwrap();
+            requests.push(request);
+        }
+        requests
+    });
+    (url, handle)
+}
+
+#[test]
+fn test() {
+    let (url, server) = stub_server(vec![
+        (200, "1\n2\n3\n"),
+        (404, "Not Found"),
+        (200, "<p>OK</p>"),
+    ]);
+    assert_eq!(
+        Ok(Response {
+            status: 200,
+            body: "1\n2\n3\n".to_string()
+        }),
+        Curl.get(&format!("{}/2021/day/1/input", url), "session=abc")
+    );
+    assert_eq!(
+        404,
+        Curl.get(&format!("{}/2021/day/2/input", url), "session=abc")
+            .unwrap()
+            .status
+    );
+    assert_eq!(
+        "<p>OK</p>",
+        Curl.post(
+            &format!("{}/2021/day/1/answer", url),
+            "session=abc",
+            &[("level", "1"), ("answer", "a&b")]
+        )
+        .unwrap()
+        .body
+    );
+    let requests = server.join().unwrap();
+    assert!(requests[0].starts_with("GET /2021/day/1/input HTTP/1.1\r\n"));
+    assert!(requests[0].contains("Cookie: session=abc\r\n"));
+    assert!(requests[0].contains(USER_AGENT));
+    assert!(requests[2].starts_with("POST /2021/day/1/answer HTTP/1.1\r\n"));
+    assert!(requests[2].ends_with("\r\nlevel=1&answer=a%26b"));
+}
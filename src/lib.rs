@@ -0,0 +1,42 @@
+// Solutions to Advent of Code puzzles, plus the helpers they share
+//
+// The runner in main.rs is a thin CLI over this library; other tools can use the helpers
+// directly, eg `adventofcode::grid::Grid` or `adventofcode::intcode::Vm`.
+
+pub mod answers;
+pub mod aoc;
+pub mod bench;
+pub mod bfs;
+pub mod day;
+pub mod dijkstra;
+pub mod examples;
+pub mod grid;
+pub mod history;
+pub mod http;
+pub mod memory;
+pub mod numeric;
+pub mod ocr;
+pub mod options;
+pub mod pixel_buffer;
+pub mod render;
+pub mod runner;
+pub mod scaffold;
+pub mod space;
+pub mod status;
+pub mod submissions;
+
+pub mod y2015;
+pub mod y2016;
+pub mod y2018;
+pub mod y2019;
+pub mod y2021;
+pub mod y2025;
+
+// the 2019 Intcode computer, used by many of that year's puzzles
+pub use y2019::intcode;
+
+// a test for each example input (see examples.rs), generated by build.rs
+#[cfg(test)]
+mod example_tests {
+    include!(concat!(env!("OUT_DIR"), "/example_tests.rs"));
+}
@@ -1,151 +1,563 @@
-use std::io::{self, Read, Write};
-use std::time::Instant;
-
-mod bfs;
-mod dijkstra;
-mod grid;
-mod numeric;
-mod pixel_buffer;
-
-mod y2015;
-mod y2016;
-mod y2018;
-mod y2019;
-mod y2021;
-mod y2025;
-
-struct Solver {
-    year: u32,
-    day: u32,
-    run: fn(&str),
-}
+use chrono::Datelike;
+use itertools::Itertools;
+use std::path::Path;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use adventofcode::answers::{Answers, Verdict};
+use adventofcode::day::Answer;
+use adventofcode::history::{self, Baseline, History};
+use adventofcode::runner::{
+    Runner, Solver, abandoned, all_solvers, install_panic_hook, read_input, run, run_days,
+};
+use adventofcode::{aoc, bench, http, memory, options, scaffold, status, submissions};
 
 fn main() {
-    let args: Vec<_> = std::env::args().collect();
+    install_panic_hook();
+    let mut args: Vec<_> = std::env::args().skip(1).collect();
+    let mut runner = Runner {
+        check: extract_flag(&mut args, "--check"),
+        save_answers: extract_flag(&mut args, "--save-answers"),
+        json: extract_flag(&mut args, "--json"),
+        ..Default::default()
+    };
+    let parallel = extract_flag(&mut args, "--parallel");
+    if parallel && memory::enabled() {
+        // the allocation counters would mix up the days running at the same time
+        eprintln!("--parallel can't be used when built with the memory feature");
+        std::process::exit(1);
+    }
+    options::RunOptions {
+        debug: extract_flag(&mut args, "--debug"),
+        interactive: extract_flag(&mut args, "--interactive"),
+        visualise: extract_flag(&mut args, "--visualise"),
+    }
+    .set();
+    runner.examples = extract_flag(&mut args, "--examples");
+    runner.all_inputs = extract_flag(&mut args, "--all-inputs");
+    // examples are always checked against their expected answers
+    runner.check |= runner.examples;
+    runner.check |= runner.save_answers;
+    runner.input = extract_option(&mut args, "--input");
+    runner.timeout = extract_option(&mut args, "--timeout").map(|secs| {
+        secs.parse()
+            .ok()
+            .filter(|&secs: &f64| secs > 0.0)
+            .and_then(|secs| Duration::try_from_secs_f64(secs).ok())
+            .unwrap_or_else(|| {
+                eprintln!(
+                    "Invalid timeout: {} (expected a number of seconds above 0)",
+                    secs
+                );
+                eprintln!("Usage: cargo run --timeout secs [options] year [days]");
+                std::process::exit(1);
+            })
+    });
+    runner.part = extract_option(&mut args, "--part").map(|part| match part.as_str() {
+        "1" => 1,
+        "2" => 2,
+        _ => {
+            eprintln!("--part must be 1 or 2");
+            std::process::exit(1);
+        }
+    });
+    runner.compare = extract_option(&mut args, "--compare").map(|baseline| {
+        Baseline::try_from(baseline.as_str()).unwrap_or_else(|e| {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        })
+    });
+    runner.threshold = extract_option(&mut args, "--threshold").map_or(10.0, |pct| {
+        pct.parse()
+            .ok()
+            .filter(|pct: &f64| pct.is_finite() && *pct >= 0.0)
+            .unwrap_or_else(|| {
+                eprintln!(
+                    "Invalid threshold: {} (expected a percentage of 0 or more)",
+                    pct
+                );
+                std::process::exit(1);
+            })
+    });
+    if runner.compare.is_some() {
+        runner.history = Some(Arc::new(History::load()));
+    }
+    if args.len() == 3 && args[2] == "-" {
+        runner.input = args.pop();
+    }
+    if runner.input.is_some() && args.len() != 2 && args.first().is_none_or(|arg| arg != "bench") {
+        eprintln!("--input can only be used with a single day");
+        std::process::exit(1);
+    }
+    // answers/ only holds answers for the real inputs
+    if runner.save_answers && (runner.input.is_some() || runner.examples || runner.all_inputs) {
+        eprintln!("--save-answers can't be used with --input, --examples or --all-inputs");
+        std::process::exit(1);
+    }
+    // only timings of the real inputs run one at a time are comparable
+    runner.record = runner.input.is_none() && !runner.examples && !runner.all_inputs && !parallel;
 
     let solvers = all_solvers();
 
-    if args.len() == 3 {
-        run(&solvers, args[1].parse().unwrap(), args[2].parse().unwrap());
+    if args.first().is_some_and(|arg| arg == "fetch") && args.len() <= 3 {
+        fetch(
+            args.get(1).map(|year| year.parse().unwrap()),
+            args.get(2).map(|day| day.parse().unwrap()),
+        );
+    } else if args == ["status"] || args == ["status", "--markdown"] {
+        status(&solvers, args.len() == 2);
+    } else if args.first().is_some_and(|arg| arg == "new") && args.len() <= 3 {
+        let today = aoc::eastern_now();
+        new_day(
+            args.get(1)
+                .map_or(today.year() as u32, |year| year.parse().unwrap()),
+            args.get(2).map_or(today.day(), |day| day.parse().unwrap()),
+        );
+    } else if args.first().is_some_and(|arg| arg == "submit") && args.len() == 4 {
+        runner.part = match args[3].as_str() {
+            "1" => Some(1),
+            "2" => Some(2),
+            _ => {
+                eprintln!("part must be 1 or 2");
+                std::process::exit(1);
+            }
+        };
+        submit(
+            &solvers,
+            &mut runner,
+            args[1].parse().unwrap(),
+            args[2].parse().unwrap(),
+        );
+    } else if args.first().is_some_and(|arg| arg == "bench") && (3..=4).contains(&args.len()) {
+        let Some(n) = args
+            .get(3)
+            .map_or(Some(10), |n| n.parse().ok().filter(|&n| n >= 1))
+        else {
+            eprintln!("Invalid repetitions: {} (expected at least 1)", args[3]);
+            eprintln!("Usage: cargo run bench [--input path] year day [repetitions]");
+            std::process::exit(1);
+        };
+        bench(
+            &solvers,
+            args[1].parse().unwrap(),
+            args[2].parse().unwrap(),
+            n,
+            runner.input.as_deref(),
+        );
     } else if args.len() == 2 {
+        let year = args[0].parse().unwrap();
+        let Some(days) = parse_days(&args[1]) else {
+            eprintln!(
+                "Invalid days: {} (expected eg 5, 1-10 or 5,7,9, each day once)",
+                args[1]
+            );
+            std::process::exit(1);
+        };
+        if days.len() == 1 {
+            run(&solvers, &mut runner, year, days[0]);
+        } else if runner.input.is_some() {
+            eprintln!("--input can only be used with a single day");
+            std::process::exit(1);
+        } else {
+            let t0 = Instant::now();
+            run_days(&solvers, &mut runner, year, &days, parallel);
+            if !runner.json {
+                print_total(&runner, t0, parallel);
+            }
+        }
+    } else if args.len() == 1 && args[0] == "all" {
         let t0 = Instant::now();
-        let year = args[1].parse().unwrap();
-        for day in 1..=25 {
-            run(&solvers, year, day);
+        for year in solvers.iter().map(|s| s.year).dedup() {
+            run_days(&solvers, &mut runner, year, &ALL_DAYS, parallel);
         }
+        if !runner.json {
+            print_summary(&solvers, &runner);
+            print_total(&runner, t0, parallel);
+        }
+    } else if args.len() == 1 {
+        let t0 = Instant::now();
+        let year = args[0].parse().unwrap();
+        run_days(&solvers, &mut runner, year, &ALL_DAYS, parallel);
+        if !runner.json {
+            print_total(&runner, t0, parallel);
+        }
+    } else {
+        eprintln!("Usage: cargo run [options] year [days]");
+        eprintln!("       cargo run [options] all");
+        eprintln!("       cargo run bench [--input path] year day [repetitions]");
+        eprintln!("       cargo run fetch [year [day]]");
+        eprintln!("       cargo run new [year [day]]");
+        eprintln!("       cargo run status [--markdown]");
+        eprintln!("       cargo run submit year day part");
+        eprintln!();
+        eprintln!("Options:");
+        eprintln!("  --check           compare results against answers/YYYY/dayN.txt");
+        eprintln!("  --save-answers    as --check, saving answers that aren't there yet");
+        eprintln!("  --json            output one JSON record per part");
+        eprintln!("  --parallel        run a year's days across all CPUs");
+        eprintln!("  --input path      read a single day's input from path (- for stdin)");
+        eprintln!("  --timeout secs    give up on a part after this long (it keeps running, so");
+        eprintln!(
+            "                    later parts' timings and memory aren't recorded or compared)"
+        );
+        eprintln!("  --part n          only run part 1 or part 2");
+        eprintln!("  --examples        run examples/YYYY/dayN/*.txt, checking expected answers");
+        eprintln!(
+            "  --all-inputs      run input/YYYY/dayN.txt and input/YYYY/dayN/*.txt, as a table"
+        );
+        eprintln!("  --compare run     compare timings against the previous or best run");
+        eprintln!(
+            "  --threshold pct   with --compare, highlight parts this much slower (default 10)"
+        );
+        eprintln!("  --debug           let solvers print diagnostic output");
+        eprintln!("  --interactive     play puzzles that are games yourself (2019 day 25)");
+        eprintln!("  --visualise       let solvers draw their progress (eg 2019 day 13)");
+        eprintln!();
+        eprintln!("Days may be a single day, a range (1-10) or a list (5,7,9)");
+        std::process::exit(1);
+    }
+
+    if let Err(e) = History::append(&runner.timings) {
+        eprintln!("Can't write {}: {}", history::FILENAME, e);
+    }
+    if abandoned() && (runner.record || runner.compare.is_some()) {
+        eprintln!("Timings after the first timeout weren't recorded or compared");
+    }
+    if let Some(baseline) = runner.compare
+        && !runner.json
+    {
         println!(
             "{:>80}",
-            format!("TOTAL: {:.2}s", t0.elapsed().as_secs_f64())
+            format!(
+                "SLOWER: {}{} (>{}% vs {} run)",
+                runner.slower,
+                if memory::enabled() {
+                    format!("  BIGGER: {}", runner.bigger)
+                } else {
+                    String::new()
+                },
+                runner.threshold,
+                if baseline == Baseline::Best {
+                    "best"
+                } else {
+                    "previous"
+                }
+            )
         );
-    } else {
-        eprintln!("Usage: cargo run year [day]");
+    }
+    if runner.check {
+        let count = |v| {
+            runner
+                .verdicts
+                .iter()
+                .filter(|&&verdict| verdict == v)
+                .count()
+        };
+        if !runner.json {
+            println!(
+                "{:>80}",
+                format!(
+                    "PASS: {}  FAIL: {}  NEW: {}{}",
+                    count(Verdict::Pass),
+                    count(Verdict::Fail),
+                    count(Verdict::New),
+                    if runner.save_answers { " (saved)" } else { "" }
+                )
+            );
+        }
+        if count(Verdict::Fail) > 0 {
+            std::process::exit(1);
+        }
+    }
+    if runner.failures > 0 {
+        eprintln!("{} part(s) panicked or timed out", runner.failures);
         std::process::exit(1);
     }
 }
 
-fn must_extract_year(s: &str) -> u32 {
-    s.strip_prefix("y").unwrap().parse().unwrap()
+fn print_total(runner: &Runner, t0: Instant, parallel: bool) {
+    println!(
+        "{:>80}",
+        if parallel {
+            format!(
+                "TOTAL: {:.2}s (CPU: {:.2}s)",
+                t0.elapsed().as_secs_f64(),
+                runner.cpu_time.as_secs_f64()
+            )
+        } else {
+            format!("TOTAL: {:.2}s", t0.elapsed().as_secs_f64())
+        }
+    );
 }
 
-fn must_extract_day(s: &str) -> u32 {
-    s.strip_prefix("day").unwrap().parse().unwrap()
+// per year: days implemented, days run, total time and the slowest day
+fn print_summary(solvers: &[Solver], runner: &Runner) {
+    println!();
+    println!(
+        "{:6}{:>6}{:>6}{:>10}  SLOWEST",
+        "YEAR", "DAYS", "RUN", "TIME"
+    );
+    for year in solvers.iter().map(|s| s.year).dedup() {
+        let times: Vec<_> = runner
+            .day_times
+            .iter()
+            .filter(|(y, _, _)| *y == year)
+            .collect();
+        println!(
+            "{:<6}{:>6}{:>6}{:>9.2}s  {}",
+            year,
+            solvers.iter().filter(|s| s.year == year).count(),
+            times.len(),
+            times
+                .iter()
+                .map(|(_, _, t)| *t)
+                .sum::<Duration>()
+                .as_secs_f64(),
+            times
+                .iter()
+                .max_by_key(|(_, _, t)| *t)
+                .map_or("-".to_string(), |(_, day, t)| format!(
+                    "day {:02} ({:.2}s)",
+                    day,
+                    t.as_secs_f64()
+                ))
+        );
+    }
 }
 
-macro_rules! solvers {
-    ($year:tt $($day:tt),*) => {
-        vec![$(
-        {
-            let year = must_extract_year(stringify!($year));
-            let day = must_extract_day(stringify!($day));
-            Solver {
-                year,
-                day,
-                run: |input: &str| {
-                    let year = must_extract_year(stringify!($year));
-                    let day = must_extract_day(stringify!($day));
-                    let t0 = Instant::now();
-                    let input = $year::$day::parse_input(input);
-                    measure(&format!("{} day {:02} part 1", year, day), t0, || {
-                        $year::$day::part1(&input).to_string()
-                    });
-                    let t0 = Instant::now();
-                    measure(&format!("{} day {:02} part 2", year, day), t0, || {
-                        $year::$day::part2(&input).to_string()
-                    });
-                },
+const ALL_DAYS: [u32; 25] = [
+    1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25,
+];
+
+// parses a list of days such as "5", "1-10" or "1-3,7,9".  Reversed ranges and days that
+// appear more than once are invalid.
+fn parse_days(s: &str) -> Option<Vec<u32>> {
+    let mut days = vec![];
+    for item in s.split(',') {
+        if let Some((from, to)) = item.split_once('-') {
+            let (from, to) = (from.parse::<u32>().ok()?, to.parse().ok()?);
+            if from > to {
+                return None;
             }
+            days.extend(from..=to);
+        } else {
+            days.push(item.parse().ok()?);
         }
-        ,)*]
+    }
+    if days.is_empty()
+        || days.iter().any(|day| !ALL_DAYS.contains(day))
+        || days.iter().duplicates().next().is_some()
+    {
+        return None;
+    }
+    Some(days)
+}
+
+#[test]
+fn test_parse_days() {
+    assert_eq!(Some(vec![5]), parse_days("5"));
+    assert_eq!(Some(vec![1, 2, 3, 4]), parse_days("1-4"));
+    assert_eq!(Some(vec![5, 7, 9]), parse_days("5,7,9"));
+    assert_eq!(Some(vec![1, 2, 3, 7]), parse_days("1-3,7"));
+    assert_eq!(None, parse_days("0"));
+    assert_eq!(None, parse_days("20-26"));
+    assert_eq!(None, parse_days("5-3"));
+    assert_eq!(None, parse_days("x"));
+    assert_eq!(None, parse_days("5-3,7"));
+    assert_eq!(None, parse_days("3,3"));
+    assert_eq!(None, parse_days("1-4,2"));
+}
+
+// removes flag from args, returning true if it was present
+fn extract_flag(args: &mut Vec<String>, flag: &str) -> bool {
+    let len = args.len();
+    args.retain(|arg| arg != flag);
+    args.len() != len
+}
+
+// removes option and its value from args, returning the value if it was present
+fn extract_option(args: &mut Vec<String>, option: &str) -> Option<String> {
+    let i = args.iter().position(|arg| arg == option)?;
+    if i + 1 == args.len() {
+        eprintln!("{} requires a value", option);
+        std::process::exit(1);
+    }
+    args.remove(i);
+    Some(args.remove(i))
+}
+
+// Runs each phase of a single day repeatedly and reports statistics on each
+fn bench(solvers: &[Solver], year: u32, day: u32, n: usize, path: Option<&str>) {
+    let Some(solver) = solvers.iter().find(|s| s.year == year && s.day == day) else {
+        eprintln!("{} day {:02}: No solver", year, day);
+        std::process::exit(1);
     };
+    let Some(input) = read_input(year, day, path) else {
+        std::process::exit(1);
+    };
+    println!(
+        "{:20}{}",
+        format!("{} day {:02} x{}", year, day, n),
+        bench::header()
+    );
+    for (label, times) in ["parse", "part 1", "part 2"]
+        .iter()
+        .zip((solver.bench)(&input, n))
+    {
+        println!("{:20}{}", label, bench::Stats::new(&times));
+    }
 }
 
-fn all_solvers() -> Vec<Solver> {
-    vec![
-        solvers!(y2015
-            day1, day2, day3, day4, day5, day6, day7, day8, day9, day10, day11, day12, day13,
-            day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25
-        ),
-        solvers!(y2016
-            day1, day2, day3, day4, day5, day6, day7, day8
-        ),
-        solvers!(y2018
-            day1, day2, day3, day4, day5, day6, day7, day8, day9, day10, day11, day12, day13,
-            day14, day15, day16
-        ),
-        solvers!(y2019
-            day1, day2, day3, day4, day5, day6, day7, day8, day9, day10, day11, day12, day13,
-            day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25
-        ),
-        solvers!(y2021
-            day1, day2, day3, day4, day5, day6, day7, day8, day9, day10, day11, day12, day13,
-            day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25
-        ),
-        solvers!(y2025
-            day1, day2, day3, day4, day5, day6
-        ),
-    ]
-    .into_iter()
-    .flatten()
-    .collect()
+// Downloads any released inputs that are missing from input/
+fn fetch(year: Option<u32>, day: Option<u32>) {
+    match fetch_inputs(year, day) {
+        Ok(0) => println!("Nothing to download"),
+        Ok(_) => (),
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        }
+    }
 }
 
-fn run(solvers: &[Solver], year: u32, day: u32) {
-    if let Some(solver) = solvers.iter().find(|s| s.year == year && s.day == day) {
-        let filename = format!("input/{}/day{}.txt", year, day);
-        if let Ok(input) = read_file(&filename) {
-            (solver.run)(&input)
+fn fetch_inputs(year: Option<u32>, day: Option<u32>) -> Result<usize, String> {
+    let session = aoc::session_token()?;
+    let mut client = aoc::Client::new(http::Curl, session);
+    let days = aoc::released_days(year, day, aoc::eastern_now());
+    aoc::fetch(&mut client, Path::new("input"), &days)
+}
+
+// Prints a calendar of each year's progress
+fn status(solvers: &[Solver], markdown: bool) {
+    let now = aoc::eastern_now();
+    let years = (2015..=now.year() as u32).filter(|&year| aoc::released(year, 1, now));
+    for (i, year) in years.enumerate() {
+        let days: Vec<_> = (1..=aoc::last_day(year))
+            .map(|day| {
+                let answers = Answers::load(year, day);
+                status::DayStatus {
+                    released: aoc::released(year, day, now),
+                    implemented: solvers.iter().any(|s| s.year == year && s.day == day),
+                    input: aoc::input_path(Path::new("input"), year, day).exists(),
+                    answered: [answers.get(1).is_some(), answers.get(2).is_some()],
+                    part2_na: day == aoc::last_day(year),
+                }
+            })
+            .collect();
+        if i > 0 {
+            println!();
+        }
+        if markdown {
+            print!("{}", status::markdown(year, &days));
         } else {
-            eprintln!("{} day {:02}: Can't read {}", year, day, filename);
+            print!("{}", status::calendar(year, &days));
         }
     }
+    if !markdown {
+        println!();
+        println!("{}", status::LEGEND);
+    }
 }
 
-fn measure<F>(label: &str, t0: Instant, f: F)
-where
-    F: FnOnce() -> String,
-{
-    print!("{}: ", label);
-    io::stdout().flush().unwrap();
-    let result = f();
-    println!(
-        "{:53} {1:5.2}s",
-        if result.contains('\n') {
-            result.lines().next().unwrap()
-        } else {
-            &result
-        },
-        t0.elapsed().as_secs_f64()
-    );
-    if result.contains('\n') {
-        for line in result.lines().skip(1) {
-            println!("{:20}{}", "", line);
+// Creates the files for a new day (today's by default) and downloads its input if possible
+fn new_day(year: u32, day: u32) {
+    if year < 2015 || !(1..=aoc::last_day(year)).contains(&day) {
+        eprintln!("{} day {:02}: No such puzzle", year, day);
+        std::process::exit(1);
+    }
+    match scaffold::new_day(Path::new("."), year, day) {
+        Ok(paths) => {
+            for path in paths {
+                println!("{}", path.display());
+            }
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(1);
         }
     }
+    if aoc::released(year, day, aoc::eastern_now())
+        && let Err(e) = fetch_inputs(Some(year), Some(day))
+    {
+        eprintln!("Input not downloaded: {}", e);
+    }
 }
 
-fn read_file(filename: &str) -> Result<String, std::io::Error> {
-    let mut f = std::fs::File::open(filename)?;
-    let mut input = String::new();
-    f.read_to_string(&mut input)?;
-    Ok(input)
+// Solves one part of a day and submits the answer, unless the submissions ledger shows that
+// it's wrong.  Correct answers are saved to answers/YYYY/dayN.txt.
+fn submit(solvers: &[Solver], runner: &mut Runner, year: u32, day: u32) {
+    let part = runner.part.unwrap();
+    if !solvers.iter().any(|s| s.year == year && s.day == day) {
+        eprintln!("{} day {:02}: No solver", year, day);
+        eprintln!(
+            "Not submitting: there is nothing to solve part {} with",
+            part
+        );
+        std::process::exit(1);
+    }
+    run(solvers, runner, year, day);
+    let answer = match runner.answer.take() {
+        Some(Answer::Art(_, e)) => {
+            eprintln!("OCR failed: {}", e);
+            eprintln!("Can't submit a picture, read it and submit the letters by hand");
+            std::process::exit(1);
+        }
+        Some(answer) if answer.is_answer() => answer.to_string(),
+        Some(answer) => {
+            eprintln!("Not submitting: part {} has no answer ({})", part, answer);
+            std::process::exit(1);
+        }
+        // the runner has already reported why
+        None if runner.failures > 0 => {
+            eprintln!("Not submitting: part {} failed", part);
+            std::process::exit(1);
+        }
+        None => {
+            eprintln!("Not submitting: the input couldn't be read");
+            std::process::exit(1);
+        }
+    };
+
+    let mut answers = Answers::load(year, day);
+    if answers.get(part).is_some() {
+        eprintln!(
+            "Not submitting: {} (answer is already in answers/{}/day{}.txt)",
+            answers.check(part, &answer).label(),
+            year,
+            day
+        );
+        std::process::exit(1);
+    }
+    let mut ledger = submissions::Ledger::load();
+    if let Err(reason) = ledger.check(year, day, part, &answer) {
+        eprintln!("Not submitting: {}", reason);
+        std::process::exit(1);
+    }
+
+    let result = aoc::session_token()
+        .and_then(|session| aoc::Client::new(http::Curl, session).submit(year, day, part, &answer));
+    let reply = match result {
+        Ok(reply) => reply,
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        }
+    };
+    println!("{} day {:02} part {}: {}", year, day, part, reply);
+    if let Err(e) = ledger.record(submissions::Submission {
+        year,
+        day,
+        part,
+        answer: answer.clone(),
+        reply: reply.clone(),
+    }) {
+        eprintln!("Can't write {}: {}", submissions::FILENAME, e);
+    }
+    if reply != aoc::Reply::Correct {
+        std::process::exit(1);
+    }
+    answers.set(part, &answer);
+    if let Err(e) = answers.save(year, day) {
+        eprintln!("Can't save answer: {}", e);
+    }
 }
@@ -0,0 +1,147 @@
+// Heap usage of each part, measured by a counting global allocator
+//
+// The allocator is only installed when built with `--features memory`; otherwise every Usage
+// is zero.  Counts are global rather than per thread, so the runner refuses --parallel when
+// they are enabled.
+
+#[cfg(feature = "memory")]
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fmt;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+#[cfg(feature = "memory")]
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+#[cfg(feature = "memory")]
+struct Counting;
+
+#[cfg(feature = "memory")]
+impl Counting {
+    fn grow(size: usize) {
+        let current = CURRENT.fetch_add(size, Ordering::Relaxed) + size;
+        PEAK.fetch_max(current, Ordering::Relaxed);
+    }
+
+    fn shrink(size: usize) {
+        CURRENT.fetch_sub(size, Ordering::Relaxed);
+    }
+}
+
+#[cfg(feature = "memory")]
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc(layout) };
+        if !ptr.is_null() {
+            Self::grow(layout.size());
+            ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc_zeroed(layout) };
+        if !ptr.is_null() {
+            Self::grow(layout.size());
+            ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) };
+        Self::shrink(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = unsafe { System.realloc(ptr, layout, new_size) };
+        if !new_ptr.is_null() {
+            Self::shrink(layout.size());
+            Self::grow(new_size);
+            ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        }
+        new_ptr
+    }
+}
+
+pub fn enabled() -> bool {
+    cfg!(feature = "memory")
+}
+
+// Heap usage since a Tracker was started
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Usage {
+    pub peak: usize,        // highest usage above the starting point
+    pub current: usize,     // still allocated at the end
+    pub allocations: usize, // number of allocations (including reallocations)
+}
+
+pub struct Tracker {
+    current: usize,
+    allocations: usize,
+}
+
+// starts tracking usage from now, resetting the peak
+pub fn track() -> Tracker {
+    let current = CURRENT.load(Ordering::Relaxed);
+    PEAK.store(current, Ordering::Relaxed);
+    Tracker {
+        current,
+        allocations: ALLOCATIONS.load(Ordering::Relaxed),
+    }
+}
+
+impl Tracker {
+    pub fn usage(&self) -> Usage {
+        Usage {
+            peak: PEAK.load(Ordering::Relaxed).saturating_sub(self.current),
+            current: CURRENT.load(Ordering::Relaxed).saturating_sub(self.current),
+            allocations: ALLOCATIONS.load(Ordering::Relaxed) - self.allocations,
+        }
+    }
+}
+
+// eg "12.3MB 4567 allocs"
+impl fmt::Display for Usage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:>7} {:>9} allocs", bytes(self.peak), self.allocations)
+    }
+}
+
+fn bytes(n: usize) -> String {
+    match n {
+        0..1024 => format!("{}B", n),
+        1024..1048576 => format!("{:.1}KB", n as f64 / 1024.0),
+        1048576..1073741824 => format!("{:.1}MB", n as f64 / 1048576.0),
+        _ => format!("{:.1}GB", n as f64 / 1073741824.0),
+    }
+}
+
+#[test]
+fn test() {
+    assert_eq!("512B", bytes(512));
+    assert_eq!("1.5KB", bytes(1536));
+    assert_eq!("12.0MB", bytes(12 * 1048576));
+    assert_eq!(
+        "  1.0MB        42 allocs",
+        Usage {
+            peak: 1048576,
+            current: 0,
+            allocations: 42
+        }
+        .to_string()
+    );
+
+    if enabled() {
+        let tracker = track();
+        let v = vec![0u8; 1 << 20];
+        drop(v);
+        let usage = tracker.usage();
+        assert!(usage.peak >= 1 << 20);
+        assert!(usage.allocations >= 1);
+    }
+}
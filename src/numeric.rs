@@ -5,7 +5,6 @@
 //
 // Implementation of:
 // https://en.wikipedia.org/wiki/Modular_exponentiation#Right-to-left_binary_method
-#[allow(dead_code)]
 pub fn mod_pow(mut b: i128, mut e: i128, m: i128) -> i128 {
     if m == 1 {
         return 0;
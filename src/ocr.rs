@@ -0,0 +1,219 @@
+// Reads the capital letters that some puzzles draw in pixels
+//
+// AoC uses two fonts: letters 4 pixels wide by 6 high (2016 day 8, 2019 days 8 and 11,
+// 2021 day 13) and 6 wide by 10 high (2018 day 10).  Letters are separated by blank columns,
+// so each run of non-blank columns is looked up in the font matching the height of the lit
+// area.  Not every letter appears in a puzzle, so these are only the ones seen so far.
+
+use crate::day::Answer;
+use crate::pixel_buffer::PixelBuffer;
+
+const SMALL: [(char, &str); 18] = [
+    ('A', ".##.|#..#|#..#|####|#..#|#..#"),
+    ('B', "###.|#..#|###.|#..#|#..#|###."),
+    ('C', ".##.|#..#|#...|#...|#..#|.##."),
+    ('E', "####|#...|###.|#...|#...|####"),
+    ('F', "####|#...|###.|#...|#...|#..."),
+    ('G', ".##.|#..#|#...|#.##|#..#|.###"),
+    ('H', "#..#|#..#|####|#..#|#..#|#..#"),
+    ('I', "###|.#.|.#.|.#.|.#.|###"),
+    ('J', "..##|...#|...#|...#|#..#|.##."),
+    ('K', "#..#|#.#.|##..|#.#.|#.#.|#..#"),
+    ('L', "#...|#...|#...|#...|#...|####"),
+    ('O', ".##.|#..#|#..#|#..#|#..#|.##."),
+    ('P', "###.|#..#|#..#|###.|#...|#..."),
+    ('R', "###.|#..#|#..#|###.|#.#.|#..#"),
+    ('S', ".###|#...|#...|.##.|...#|###."),
+    ('U', "#..#|#..#|#..#|#..#|#..#|.##."),
+    ('Y', "#...#|#...#|.#.#.|..#..|..#..|..#.."),
+    ('Z', "####|...#|..#.|.#..|#...|####"),
+];
+
+const LARGE: [(char, &str); 15] = [
+    (
+        'A',
+        "..##..|.#..#.|#....#|#....#|#....#|######|#....#|#....#|#....#|#....#",
+    ),
+    (
+        'B',
+        "#####.|#....#|#....#|#....#|#####.|#....#|#....#|#....#|#....#|#####.",
+    ),
+    (
+        'C',
+        ".####.|#....#|#.....|#.....|#.....|#.....|#.....|#.....|#....#|.####.",
+    ),
+    (
+        'E',
+        "######|#.....|#.....|#.....|#####.|#.....|#.....|#.....|#.....|######",
+    ),
+    (
+        'F',
+        "######|#.....|#.....|#.....|#####.|#.....|#.....|#.....|#.....|#.....",
+    ),
+    (
+        'G',
+        ".####.|#....#|#.....|#.....|#.....|#..###|#....#|#....#|#...##|.###.#",
+    ),
+    (
+        'H',
+        "#....#|#....#|#....#|#....#|######|#....#|#....#|#....#|#....#|#....#",
+    ),
+    (
+        'J',
+        "...###|....#.|....#.|....#.|....#.|....#.|....#.|#...#.|#...#.|.###..",
+    ),
+    (
+        'K',
+        "#....#|#...#.|#..#..|#.#...|##....|##....|#.#...|#..#..|#...#.|#....#",
+    ),
+    (
+        'L',
+        "#.....|#.....|#.....|#.....|#.....|#.....|#.....|#.....|#.....|######",
+    ),
+    (
+        'N',
+        "#....#|##...#|##...#|#.#..#|#.#..#|#..#.#|#..#.#|#...##|#...##|#....#",
+    ),
+    (
+        'P',
+        "#####.|#....#|#....#|#....#|#####.|#.....|#.....|#.....|#.....|#.....",
+    ),
+    (
+        'R',
+        "#####.|#....#|#....#|#....#|#####.|#..#..|#...#.|#...#.|#....#|#....#",
+    ),
+    (
+        'X',
+        "#....#|#....#|.#..#.|.#..#.|..##..|..##..|.#..#.|.#..#.|#....#|#....#",
+    ),
+    (
+        'Z',
+        "######|.....#|.....#|....#.|...#..|..#...|.#....|#.....|#.....|######",
+    ),
+];
+
+// The letters drawn in buffer, or an error showing the glyphs that weren't recognised
+pub fn read(buffer: &PixelBuffer) -> Result<String, String> {
+    let lit = |x: u32, y: u32| buffer.get(x, y);
+    let rows: Vec<u32> = (0..buffer.height())
+        .filter(|&y| (0..buffer.width()).any(|x| lit(x, y)))
+        .collect();
+    let (Some(&top), Some(&bottom)) = (rows.first(), rows.last()) else {
+        return Err("no letters found".to_string());
+    };
+    let font: &[(char, &str)] = match bottom - top + 1 {
+        6 => &SMALL,
+        10 => &LARGE,
+        height => return Err(format!("no font is {} pixels high", height)),
+    };
+
+    let blank = |x: u32| (top..=bottom).all(|y| !lit(x, y));
+    let mut text = String::new();
+    let mut unknown = vec![];
+    let mut x = 0;
+    while x < buffer.width() {
+        if blank(x) {
+            x += 1;
+            continue;
+        }
+        let left = x;
+        while x < buffer.width() && !blank(x) {
+            x += 1;
+        }
+        let glyph = (top..=bottom)
+            .map(|y| {
+                (left..x)
+                    .map(|x| if lit(x, y) { '#' } else { '.' })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("|");
+        match font.iter().find(|(_, pattern)| *pattern == glyph) {
+            Some((c, _)) => text.push(*c),
+            None => {
+                text.push('?');
+                unknown.push(glyph.replace('|', "\n"));
+            }
+        }
+    }
+
+    if unknown.is_empty() {
+        Ok(text)
+    } else {
+        Err(format!(
+            "unrecognised glyphs in {}:\n{}",
+            text,
+            unknown.join("\n\n")
+        ))
+    }
+}
+
+// The letters as a Text answer.  If they can't all be read, the picture is returned as Art
+// for a human to read instead, along with read's error.
+pub fn answer(buffer: &PixelBuffer) -> Answer {
+    match read(buffer) {
+        Ok(text) => Answer::Text(text),
+        Err(e) => Answer::Art(buffer.to_string(), e),
+    }
+}
+
+#[cfg(test)]
+fn draw(art: &str) -> PixelBuffer {
+    let lines: Vec<&str> = art.lines().collect();
+    let mut buffer = PixelBuffer::new(lines[0].len() as u32, lines.len() as u32);
+    for (y, line) in lines.iter().enumerate() {
+        for (x, c) in line.chars().enumerate() {
+            buffer.set(x as u32, y as u32, c == '#');
+        }
+    }
+    buffer
+}
+
+#[test]
+fn test() {
+    let small = draw(
+        "\
+..........................
+.#..#.####.###..#..#.####.
+.#..#.#....#..#.#..#....#.
+.####.###..#..#.#..#...#..
+.#..#.#....###..#..#..#...
+.#..#.#....#.#..#..#.#....
+.#..#.####.#..#..##..####.
+..........................",
+    );
+    assert_eq!(Ok("HERUZ".to_string()), read(&small));
+    assert_eq!(Answer::Text("HERUZ".to_string()), answer(&small));
+
+    let large = draw(
+        "\
+#....#..######
+#....#....#...
+#....#....#...
+#....#....#...
+######....#...
+#....#....#...
+#....#....#...
+#....#....#...
+#....#....#...
+#....#..######",
+    );
+    assert_eq!(
+        Err("unrecognised glyphs in H?:\n######\n..#...\n..#...\n..#...\n..#...\n..#...\n..#...\n..#...\n..#...\n######".to_string()),
+        read(&large)
+    );
+
+    assert_eq!(
+        Answer::Art(large.to_string(), read(&large).unwrap_err()),
+        answer(&large)
+    );
+
+    assert_eq!(
+        Err("no letters found".to_string()),
+        read(&PixelBuffer::new(5, 6))
+    );
+    assert_eq!(
+        Err("no font is 2 pixels high".to_string()),
+        read(&draw("##\n##"))
+    );
+}
@@ -0,0 +1,25 @@
+// Options from the command line that change how solvers behave, rather than their answers
+//
+// Set once by main before any solver runs; solvers (and tests) that run without them being set
+// see the defaults.
+
+use std::sync::OnceLock;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct RunOptions {
+    pub debug: bool,       // print diagnostic output while solving
+    pub interactive: bool, // let the user play, where a puzzle is a game (2019 day 25)
+    pub visualise: bool,   // draw the puzzle's state as it is solved
+}
+
+static OPTIONS: OnceLock<RunOptions> = OnceLock::new();
+
+impl RunOptions {
+    pub fn set(self) {
+        OPTIONS.set(self).expect("run options already set");
+    }
+
+    pub fn get() -> Self {
+        OPTIONS.get().copied().unwrap_or_default()
+    }
+}
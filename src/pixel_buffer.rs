@@ -1,7 +1,8 @@
 use std::fmt;
 
 // screen/buffer of pixels.  (0,0) is top left.
-// outputs using unicode block elements mapping 4 pixels per character
+// outputs using unicode block elements mapping 4 pixels per character, or can be read as
+// letters by ocr.rs
 //
 // could probably replace 2016 day 8 Screen with light/rotate fns
 pub struct PixelBuffer {
@@ -30,8 +31,16 @@ impl PixelBuffer {
         self.bits[(x + y * self.width) as usize] = v
     }
 
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
     pub fn get(&self, x: u32, y: u32) -> bool {
-        if x >= self.width || y >= self.width {
+        if x >= self.width || y >= self.height {
             false
         } else {
             self.bits[(x + y * self.width) as usize]
@@ -0,0 +1,223 @@
+// Draws a Grid or SparseGrid as text, one char per cell
+//
+// Cell types implement Render to choose their char (and optionally a colour).  A View draws
+// the cells with an overlay on top, such as units, a path or a cursor, and can add a note to
+// the end of a row.  Colour is drawn with ANSI escapes, so is off unless asked for.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::grid::{Grid, Pos, SparseGrid};
+
+pub trait Render {
+    fn to_char(&self) -> char;
+
+    fn colour(&self) -> Option<Colour> {
+        None
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Colour {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    Grey,
+}
+
+impl Colour {
+    // ANSI foreground colour
+    fn code(&self) -> u8 {
+        match self {
+            Colour::Red => 31,
+            Colour::Green => 32,
+            Colour::Yellow => 33,
+            Colour::Blue => 34,
+            Colour::Magenta => 35,
+            Colour::Cyan => 36,
+            Colour::Grey => 90,
+        }
+    }
+}
+
+// A rectangle of cells that a View can draw
+pub trait Canvas {
+    type Cell: Render;
+
+    fn nw(&self) -> Pos;
+    fn se(&self) -> Pos;
+    fn cell(&self, p: Pos) -> &Self::Cell;
+}
+
+impl<T> Canvas for Grid<T>
+where
+    T: Render + Clone,
+{
+    type Cell = T;
+
+    fn nw(&self) -> Pos {
+        Pos {
+            x: self.minx(),
+            y: self.miny(),
+        }
+    }
+
+    fn se(&self) -> Pos {
+        Pos {
+            x: self.maxx(),
+            y: self.maxy(),
+        }
+    }
+
+    fn cell(&self, p: Pos) -> &T {
+        self.get(p)
+    }
+}
+
+impl<T> Canvas for SparseGrid<T>
+where
+    T: Render + Clone,
+{
+    type Cell = T;
+
+    fn nw(&self) -> Pos {
+        Pos {
+            x: self.minx(),
+            y: self.miny(),
+        }
+    }
+
+    fn se(&self) -> Pos {
+        Pos {
+            x: self.maxx(),
+            y: self.maxy(),
+        }
+    }
+
+    fn cell(&self, p: Pos) -> &T {
+        self.get(p)
+    }
+}
+
+// eg `println!("{}", View::new(&grid).overlay(robot, '@').colour(true))`
+pub struct View<'a, C> {
+    canvas: &'a C,
+    overlay: HashMap<Pos, (char, Option<Colour>)>,
+    notes: HashMap<i32, String>,
+    colour: bool,
+}
+
+impl<'a, C> View<'a, C>
+where
+    C: Canvas,
+{
+    pub fn new(canvas: &'a C) -> Self {
+        Self {
+            canvas,
+            overlay: HashMap::new(),
+            notes: HashMap::new(),
+            colour: false,
+        }
+    }
+
+    // draws c at p instead of the cell there (replacing any earlier overlay)
+    pub fn overlay(self, p: Pos, c: char) -> Self {
+        self.overlay_with(p, c, None)
+    }
+
+    pub fn overlay_coloured(self, p: Pos, c: char, colour: Colour) -> Self {
+        self.overlay_with(p, c, Some(colour))
+    }
+
+    pub fn overlay_all(mut self, ps: impl IntoIterator<Item = Pos>, c: char) -> Self {
+        for p in ps {
+            self = self.overlay(p, c);
+        }
+        self
+    }
+
+    fn overlay_with(mut self, p: Pos, c: char, colour: Option<Colour>) -> Self {
+        self.overlay.insert(p, (c, colour));
+        self
+    }
+
+    // appends text after the last cell of row y
+    pub fn note(mut self, y: i32, text: &str) -> Self {
+        self.notes.entry(y).or_default().push_str(text);
+        self
+    }
+
+    pub fn colour(mut self, colour: bool) -> Self {
+        self.colour = colour;
+        self
+    }
+}
+
+impl<C> fmt::Display for View<'_, C>
+where
+    C: Canvas,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (nw, se) = (self.canvas.nw(), self.canvas.se());
+        for y in nw.y..=se.y {
+            for x in nw.x..=se.x {
+                let p = Pos { x, y };
+                let (c, colour) = self.overlay.get(&p).copied().unwrap_or_else(|| {
+                    let cell = self.canvas.cell(p);
+                    (cell.to_char(), cell.colour())
+                });
+                match colour.filter(|_| self.colour) {
+                    Some(colour) => write!(f, "\x1b[{}m{}\x1b[0m", colour.code(), c)?,
+                    None => write!(f, "{}", c)?,
+                }
+            }
+            if let Some(note) = self.notes.get(&y) {
+                f.write_str(note)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn test() {
+    #[derive(Clone)]
+    struct Cell(bool);
+
+    impl Render for Cell {
+        fn to_char(&self) -> char {
+            if self.0 { '#' } else { '.' }
+        }
+
+        fn colour(&self) -> Option<Colour> {
+            self.0.then_some(Colour::Grey)
+        }
+    }
+
+    let grid = Grid::from_input("#..\n.#.\n", Cell(false), |c| Cell(c == '#'));
+    assert_eq!("#..\n.#.\n", View::new(&grid).to_string());
+    assert_eq!(
+        "#*.\n.@. 1 unit\n",
+        View::new(&grid)
+            .overlay_all([Pos { x: 1, y: 0 }, Pos { x: 1, y: 1 }], '*')
+            .overlay(Pos { x: 1, y: 1 }, '@')
+            .note(1, " 1 unit")
+            .to_string()
+    );
+    assert_eq!(
+        "\x1b[90m#\x1b[0m\x1b[31m@\x1b[0m.\n.\x1b[90m#\x1b[0m.\n",
+        View::new(&grid)
+            .overlay_coloured(Pos { x: 1, y: 0 }, '@', Colour::Red)
+            .colour(true)
+            .to_string()
+    );
+
+    let mut sparse = SparseGrid::new(Cell(false));
+    sparse.set(Pos { x: -1, y: 2 }, Cell(true));
+    sparse.set(Pos { x: 1, y: 3 }, Cell(true));
+    assert_eq!("#..\n..#\n", View::new(&sparse).to_string());
+}
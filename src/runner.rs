@@ -0,0 +1,710 @@
+// Runs solvers, timing each part and reporting (and optionally checking) its answer
+//
+// Each day runs on its own thread so that panics and timeouts are contained.  Output is text
+// or JSON lines, and can be buffered so that days run in parallel print in order.
+
+use std::cell::{Cell, RefCell};
+use std::hint::black_box;
+use std::io::{self, Read, Write};
+use std::panic::{self, AssertUnwindSafe};
+use std::path::Path;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use serde_json::json;
+
+use crate::answers::{Answers, Verdict};
+use crate::day::{Answer, Day};
+use crate::history::{self, Baseline, History};
+use crate::{aoc, examples, memory};
+
+pub struct Solver {
+    pub year: u32,
+    pub day: u32,
+    // parses input then runs each part through Runner::measure
+    pub run: fn(String, &mut Runner),
+    // parses input and runs each part n times, returning the timings of each phase
+    pub bench: fn(&str, usize) -> [Vec<Duration>; 3],
+}
+
+fn must_extract_year(s: &str) -> u32 {
+    s.strip_prefix("y").unwrap().parse().unwrap()
+}
+
+fn must_extract_day(s: &str) -> u32 {
+    s.strip_prefix("day").unwrap().parse().unwrap()
+}
+
+macro_rules! solvers {
+    ($year:tt $($day:tt),*) => {
+        vec![$(
+            Solver::new::<crate::$year::$day::Solution>(
+                must_extract_year(stringify!($year)),
+                must_extract_day(stringify!($day)),
+            ),
+        )*]
+    };
+}
+
+impl Solver {
+    fn new<D: Day>(year: u32, day: u32) -> Self {
+        Self {
+            year,
+            day,
+            run: run_day::<D>,
+            bench: bench_day::<D>,
+        }
+    }
+}
+
+// Messages from the thread running a day back to the runner
+enum Event {
+    Parsed(Result<Duration, String>),
+    Solved(Outcome, Duration, memory::Usage),
+}
+
+// Result of running one part
+enum Outcome {
+    Solved(Answer),
+    Panicked(String),
+    TimedOut,
+    Skipped, // not run because parsing or an earlier part failed
+}
+
+// set once a timed out part has been left running
+static ABANDONED: AtomicBool = AtomicBool::new(false);
+
+// true if a part has timed out, so timings and memory use are no longer reliable
+pub fn abandoned() -> bool {
+    ABANDONED.load(Ordering::Relaxed)
+}
+
+// some solvers recurse deeply, so give them as much stack as the main thread would have
+const STACK_SIZE: usize = 64 * 1024 * 1024;
+
+// Runs the day on its own thread so that a panic or timeout only fails the part that caused it.
+// After a timeout the thread is abandoned and left running in the background, where it skews
+// the time and memory of everything run after it.
+fn run_day<D: Day>(input: String, runner: &mut Runner) {
+    let parts = runner.parts();
+    let (tx, rx) = mpsc::channel();
+    let thread_parts = parts.clone();
+    thread::Builder::new()
+        .name(format!("{} day {:02}", runner.year, runner.day))
+        .stack_size(STACK_SIZE)
+        .spawn(move || {
+            // send errors mean the runner has given up on this day
+            let t0 = Instant::now();
+            let input = match catch_panic(|| D::parse_input(&input)) {
+                Ok(input) => input,
+                Err(msg) => {
+                    let _ = tx.send(Event::Parsed(Err(msg)));
+                    return;
+                }
+            };
+            let _ = tx.send(Event::Parsed(Ok(t0.elapsed())));
+            for part in thread_parts {
+                let solve = if part == 1 { D::part1 } else { D::part2 };
+                let tracker = memory::track();
+                let t0 = Instant::now();
+                let outcome = match catch_panic(|| solve(&input)) {
+                    Ok(answer) => Outcome::Solved(answer),
+                    Err(msg) => Outcome::Panicked(msg),
+                };
+                let solve_time = t0.elapsed();
+                let usage = tracker.usage();
+                if tx.send(Event::Solved(outcome, solve_time, usage)).is_err() {
+                    return;
+                }
+            }
+        })
+        .unwrap();
+
+    let timeout = runner.timeout.unwrap_or(Duration::MAX);
+    let mut failure = match rx.recv_timeout(timeout) {
+        Ok(Event::Parsed(Ok(parse_time))) => {
+            runner.parsed(parse_time);
+            None
+        }
+        Ok(Event::Parsed(Err(msg))) => Some(Outcome::Panicked(format!("parse_input: {}", msg))),
+        _ => Some(Outcome::TimedOut),
+    };
+    let mut abandoned = false;
+    for part in parts {
+        runner.measure(part, || {
+            let no_usage = memory::Usage::default();
+            if abandoned {
+                return (Outcome::Skipped, Duration::ZERO, no_usage);
+            }
+            if let Some(outcome) = failure.take() {
+                abandoned = true;
+                return (outcome, Duration::ZERO, no_usage);
+            }
+            match rx.recv_timeout(timeout) {
+                Ok(Event::Solved(outcome, solve_time, usage)) => (outcome, solve_time, usage),
+                _ => {
+                    abandoned = true;
+                    (Outcome::TimedOut, timeout, no_usage)
+                }
+            }
+        });
+    }
+}
+
+thread_local! {
+    // location of the last panic caught by catch_panic on this thread
+    static PANIC_LOCATION: RefCell<Option<String>> = const { RefCell::new(None) };
+    static CATCHING_PANICS: Cell<bool> = const { Cell::new(false) };
+}
+
+// Panics caught by catch_panic are reported in the output, so rather than printing them the
+// hook just records where they happened.  Other panics are printed as usual.
+pub fn install_panic_hook() {
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        if CATCHING_PANICS.get() {
+            PANIC_LOCATION.set(info.location().map(|l| l.to_string()));
+        } else {
+            default_hook(info);
+        }
+    }));
+}
+
+// runs f, returning the panic message (and where it happened) if it panics
+fn catch_panic<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    CATCHING_PANICS.set(true);
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    CATCHING_PANICS.set(false);
+    result.map_err(|payload| {
+        let msg = if let Some(msg) = payload.downcast_ref::<&str>() {
+            msg.to_string()
+        } else if let Some(msg) = payload.downcast_ref::<String>() {
+            msg.clone()
+        } else {
+            "unknown panic".to_string()
+        };
+        match PANIC_LOCATION.take() {
+            Some(location) => format!("{} at {}", msg, location),
+            None => msg,
+        }
+    })
+}
+
+fn bench_day<D: Day>(input: &str, n: usize) -> [Vec<Duration>; 3] {
+    let mut times: [Vec<Duration>; 3] = Default::default();
+    for _ in 0..n {
+        let t0 = Instant::now();
+        black_box(D::parse_input(black_box(input)));
+        times[0].push(t0.elapsed());
+    }
+    let input = D::parse_input(input);
+    for _ in 0..n {
+        let t0 = Instant::now();
+        black_box(D::part1(black_box(&input)));
+        times[1].push(t0.elapsed());
+    }
+    for _ in 0..n {
+        let t0 = Instant::now();
+        black_box(D::part2(black_box(&input)));
+        times[2].push(t0.elapsed());
+    }
+    times
+}
+
+// all_solvers() is generated from the src/yYYYY/dayN.rs files by build.rs
+include!(concat!(env!("OUT_DIR"), "/solvers.rs"));
+
+pub fn run(solvers: &[Solver], runner: &mut Runner, year: u32, day: u32) {
+    if runner.examples {
+        run_examples(solvers, runner, year, day);
+    } else if runner.all_inputs {
+        run_inputs(solvers, runner, year, day);
+    } else if let Some(solver) = solvers.iter().find(|s| s.year == year && s.day == day)
+        && let Some(input) = read_input(year, day, runner.input.as_deref())
+    {
+        runner.start(year, day);
+        let cpu_time = runner.cpu_time;
+        (solver.run)(input, runner);
+        runner
+            .day_times
+            .push((year, day, runner.cpu_time - cpu_time));
+    }
+}
+
+// Runs a day against each of its example inputs, checking the expected answers
+fn run_examples(solvers: &[Solver], runner: &mut Runner, year: u32, day: u32) {
+    let Some(solver) = solvers.iter().find(|s| s.year == year && s.day == day) else {
+        return;
+    };
+    let examples = examples::find(year, day);
+    if examples.is_empty() {
+        eprintln!("{} day {:02}: No examples", year, day);
+    }
+    for example in examples {
+        // `cargo run new` leaves an empty example to be filled in
+        if example.input.is_empty() {
+            eprintln!("{} day {:02}: Example {} is empty", year, day, example.name);
+            continue;
+        }
+        runner.start(year, day);
+        runner.answers = example.answers;
+        runner.example = Some(example.name);
+        (solver.run)(example.input, runner);
+    }
+}
+
+// Runs a day against its puzzle input and each alternative input in input/YYYY/dayN/,
+// printing a table of the answers and times for each
+fn run_inputs(solvers: &[Solver], runner: &mut Runner, year: u32, day: u32) {
+    let Some(solver) = solvers.iter().find(|s| s.year == year && s.day == day) else {
+        return;
+    };
+    let mut inputs = vec![];
+    if let Some(input) = read_input(year, day, None) {
+        inputs.push(examples::Example {
+            name: "default".to_string(),
+            input,
+            answers: Answers::load(year, day),
+        });
+    }
+    inputs.extend(examples::find_in(&format!("input/{}/day{}", year, day)));
+
+    if !runner.json {
+        let mut header = format!("{:16} {:>7}", "INPUT", "PARSE");
+        for part in runner.parts() {
+            header += &format!(" {:>20} {:>7}", format!("PART {}", part), "TIME");
+        }
+        runner.emit(&format!("{}\n", header.trim_end()));
+    }
+    for input in inputs {
+        runner.start(year, day);
+        if runner.check {
+            runner.answers = input.answers;
+        }
+        runner.example = Some(input.name.clone());
+        // the usual output for each part is replaced by a row of the table
+        let output = runner.output.replace(String::new());
+        (solver.run)(input.input, runner);
+        let lines = std::mem::replace(&mut runner.output, output).unwrap();
+        if runner.json {
+            runner.emit(&lines);
+        } else {
+            let row = table_row(&input.name, runner.parse_time, &runner.results);
+            runner.emit(&row);
+        }
+    }
+}
+
+// eg "alice              0.01s                 1234   0.50s PASS"
+fn table_row(name: &str, parse_time: Duration, results: &[PartResult]) -> String {
+    let mut row = format!("{:16} {:6.2}s", name, parse_time.as_secs_f64());
+    for r in results {
+        let mut answer = r.result.lines().next().unwrap_or("").to_string();
+        if r.result.contains('\n') {
+            answer += "...";
+        }
+        row += &format!(
+            " {:>20} {:6.2}s{}",
+            answer,
+            r.time.as_secs_f64(),
+            r.verdict
+                .map_or(String::new(), |v| format!(" {:4}", v.label()))
+        );
+    }
+    row.trim_end().to_string() + "\n"
+}
+
+pub fn run_days(solvers: &[Solver], runner: &mut Runner, year: u32, days: &[u32], parallel: bool) {
+    if parallel {
+        run_parallel(solvers, runner, year, days);
+    } else {
+        for &day in days {
+            run(solvers, runner, year, day);
+        }
+    }
+}
+
+// Runs days on a pool of worker threads (one per CPU).
+// Output is buffered per day and printed in day order once all days are complete.
+fn run_parallel(solvers: &[Solver], runner: &mut Runner, year: u32, days: &[u32]) {
+    let next = AtomicUsize::new(0);
+    let mut results: Vec<(u32, Runner)> = thread::scope(|s| {
+        let workers: Vec<_> = (0..num_cpus::get())
+            .map(|_| {
+                s.spawn(|| {
+                    let mut results = vec![];
+                    loop {
+                        let Some(&day) = days.get(next.fetch_add(1, Ordering::Relaxed)) else {
+                            return results;
+                        };
+                        let mut worker = runner.buffered();
+                        run(solvers, &mut worker, year, day);
+                        results.push((day, worker));
+                    }
+                })
+            })
+            .collect();
+        workers
+            .into_iter()
+            .flat_map(|worker| worker.join().unwrap())
+            .collect()
+    });
+    results.sort_by_key(|(day, _)| *day);
+    for (_, worker) in results {
+        print!("{}", worker.output.unwrap());
+        runner.verdicts.extend(worker.verdicts);
+        runner.cpu_time += worker.cpu_time;
+        runner.failures += worker.failures;
+        runner.day_times.extend(worker.day_times);
+        runner.timings.extend(worker.timings);
+        runner.slower += worker.slower;
+        runner.bigger += worker.bigger;
+    }
+}
+
+pub struct PartResult {
+    result: String,
+    time: Duration,
+    verdict: Option<Verdict>,
+}
+
+// Output options, and the state of the day currently being run
+#[derive(Default)]
+pub struct Runner {
+    pub check: bool,               // compare results against known answers
+    pub save_answers: bool,        // record results as the known answers where there are none
+    pub json: bool,                // output one JSON record per line instead of text
+    pub input: Option<String>,     // input file to use instead of input/YYYY/dayN.txt
+    pub timeout: Option<Duration>, // time allowed for parsing and for each part
+    pub part: Option<u8>,          // only run this part
+    pub examples: bool,            // run examples/YYYY/dayN/*.txt instead of the puzzle input
+    pub all_inputs: bool,          // run the puzzle input and input/YYYY/dayN/*.txt
+    pub record: bool,              // add timings to the history file
+    pub compare: Option<Baseline>, // compare timings against this earlier run
+    pub threshold: f64,            // percentage slowdown to highlight when comparing
+    pub history: Option<Arc<History>>,
+    pub year: u32,
+    pub day: u32,
+    pub answers: Answers,
+    pub example: Option<String>, // name of the example (or alternative input) being run
+    pub results: Vec<PartResult>, // parts of the current day run so far
+    pub parse_time: Duration,
+    pub cpu_time: Duration,                   // total time spent in solvers
+    pub day_times: Vec<(u32, u32, Duration)>, // (year, day, time) for each day run
+    pub output: Option<String>,               // output is collected here instead of printed if set
+    pub verdicts: Vec<Verdict>,
+    pub failures: usize,              // parts that panicked or timed out
+    pub timings: Vec<history::Entry>, // timings to add to the history file
+    pub slower: usize,                // parts slower than the threshold
+    pub bigger: usize,                // parts using more memory than the threshold
+    pub answer: Option<Answer>,       // answer to the last part solved
+}
+
+impl Runner {
+    // new runner with the same options, buffering its output
+    fn buffered(&self) -> Self {
+        Self {
+            check: self.check,
+            save_answers: self.save_answers,
+            json: self.json,
+            timeout: self.timeout,
+            part: self.part,
+            examples: self.examples,
+            all_inputs: self.all_inputs,
+            record: self.record,
+            compare: self.compare,
+            threshold: self.threshold,
+            history: self.history.clone(),
+            output: Some(String::new()),
+            ..Default::default()
+        }
+    }
+
+    fn parts(&self) -> Vec<u8> {
+        match self.part {
+            Some(part) => vec![part],
+            None => vec![1, 2],
+        }
+    }
+
+    fn emit(&mut self, s: &str) {
+        if let Some(output) = &mut self.output {
+            output.push_str(s);
+        } else {
+            print!("{}", s);
+            io::stdout().flush().unwrap();
+        }
+    }
+
+    fn start(&mut self, year: u32, day: u32) {
+        self.year = year;
+        self.day = day;
+        self.answers = if self.check {
+            Answers::load(year, day)
+        } else {
+            Answers::default()
+        };
+        self.example = None;
+        self.results.clear();
+        self.parse_time = Duration::ZERO;
+    }
+
+    fn parsed(&mut self, parse_time: Duration) {
+        self.parse_time = parse_time;
+        self.cpu_time += parse_time;
+    }
+
+    // waits for f to run a part, reporting its outcome, time taken and memory used
+    fn measure<F>(&mut self, part: u8, f: F)
+    where
+        F: FnOnce() -> (Outcome, Duration, memory::Usage),
+    {
+        let mut label = format!("{} day {:02} part {}", self.year, self.day, part);
+        if let Some(example) = &self.example {
+            label += &format!(" ({})", example);
+        }
+        if !self.json {
+            self.emit(&format!("{}: ", label));
+        }
+        let (outcome, solve_time, usage) = f();
+        if matches!(outcome, Outcome::TimedOut) {
+            ABANDONED.store(true, Ordering::Relaxed);
+        }
+        self.cpu_time += solve_time;
+        let result = match &outcome {
+            Outcome::Solved(answer) => answer.to_string(),
+            Outcome::Panicked(msg) => format!("PANIC: {}", msg),
+            Outcome::TimedOut => "TIMEOUT".to_string(),
+            Outcome::Skipped => "SKIPPED".to_string(),
+        };
+        let verdict = match &outcome {
+            // placeholders such as n/a aren't checked
+            Outcome::Solved(answer) if !answer.is_answer() => None,
+            Outcome::Solved(_) => self.check.then(|| self.answers.check(part, &result)),
+            Outcome::Panicked(_) | Outcome::TimedOut => {
+                self.failures += 1;
+                self.check.then_some(Verdict::Fail)
+            }
+            Outcome::Skipped => None,
+        };
+        self.verdicts.extend(verdict);
+        if self.save_answers && verdict == Some(Verdict::New) {
+            self.answers.set(part, &result);
+            if let Err(e) = self.answers.save(self.year, self.day) {
+                eprintln!("Can't save answer: {}", e);
+            }
+        }
+        self.results.push(PartResult {
+            result: result.clone(),
+            time: solve_time,
+            verdict,
+        });
+
+        let solved = matches!(outcome, Outcome::Solved(_));
+        // a timed out part still running in the background would skew this one's figures
+        let measured = solved && !abandoned();
+        let usage = (measured && memory::enabled()).then_some(usage);
+        if let Outcome::Solved(answer) = &outcome {
+            self.answer = Some(answer.clone());
+        }
+        if self.record && measured {
+            self.timings.push(history::Entry::new(
+                self.year,
+                self.day,
+                part,
+                self.parse_time,
+                solve_time,
+                usage.map(|usage| usage.peak),
+            ));
+        }
+        let baseline_time = match (self.compare, &self.history) {
+            (Some(baseline), Some(history)) if measured => {
+                history.baseline(baseline, self.year, self.day, part)
+            }
+            _ => None,
+        };
+        let slower = baseline_time
+            .and_then(|baseline| history::slowdown(baseline, solve_time, self.threshold));
+        if slower.is_some() {
+            self.slower += 1;
+        }
+        let baseline_memory = match (self.compare, &self.history, usage) {
+            (Some(baseline), Some(history), Some(_)) => {
+                history.baseline_memory(baseline, self.year, self.day, part)
+            }
+            _ => None,
+        };
+        let bigger = baseline_memory
+            .and_then(|baseline| history::growth(baseline, usage.unwrap().peak, self.threshold));
+        if bigger.is_some() {
+            self.bigger += 1;
+        }
+
+        if self.json {
+            let mut record = json!({
+                "year": self.year,
+                "day": self.day,
+                "part": part,
+                "parse_time": self.parse_time.as_secs_f64(),
+                "solve_time": solve_time.as_secs_f64(),
+            });
+            match &outcome {
+                Outcome::Solved(answer) => {
+                    record["kind"] = answer.kind().into();
+                    record["answer"] = answer.is_answer().then_some(result.as_str()).into();
+                    if let Answer::Art(_, e) = answer {
+                        record["error"] = e.as_str().into();
+                    }
+                }
+                Outcome::Panicked(msg) => {
+                    record["kind"] = "panic".into();
+                    record["error"] = msg.as_str().into();
+                }
+                Outcome::TimedOut => record["kind"] = "timeout".into(),
+                Outcome::Skipped => record["kind"] = "skipped".into(),
+            }
+            if let Some(example) = &self.example {
+                let key = if self.examples { "example" } else { "input" };
+                record[key] = example.as_str().into();
+            }
+            if let Some(verdict) = verdict {
+                record["verdict"] = verdict.label().into();
+            }
+            if let Some(baseline_time) = baseline_time {
+                record["baseline_time"] = baseline_time.as_secs_f64().into();
+            }
+            if let Some(slower) = slower {
+                record["slower"] = slower.into();
+            }
+            if let Some(usage) = usage {
+                record["peak_memory"] = usage.peak.into();
+                record["current_memory"] = usage.current.into();
+                record["allocations"] = usage.allocations.into();
+            }
+            if let Some(baseline_memory) = baseline_memory {
+                record["baseline_memory"] = baseline_memory.into();
+            }
+            if let Some(bigger) = bigger {
+                record["bigger"] = bigger.into();
+            }
+            self.emit(&format!("{}\n", record));
+            return;
+        }
+
+        // text output charges parsing to the first part run
+        let elapsed = if part == self.parts()[0] {
+            self.parse_time + solve_time
+        } else {
+            solve_time
+        };
+        let mut text = format!(
+            "{:width$} {:5.2}s{}{}{}{}\n",
+            if result.contains('\n') {
+                result.lines().next().unwrap()
+            } else {
+                &result
+            },
+            elapsed.as_secs_f64(),
+            usage.map_or(String::new(), |usage| format!(" {}", usage)),
+            verdict.map_or(String::new(), |v| format!(" {}", v.label())),
+            slower.map_or(String::new(), |pct| format!(" SLOWER +{:.0}%", pct)),
+            bigger.map_or(String::new(), |pct| format!(" BIGGER +{:.0}%", pct)),
+            width = 71_usize.saturating_sub(label.len())
+        );
+        if result.contains('\n') {
+            for line in result.lines().skip(1) {
+                text += &format!("{:20}{}\n", "", line);
+            }
+        }
+        if let Outcome::Solved(Answer::Art(_, e)) = &outcome {
+            for (i, line) in e.lines().enumerate() {
+                text += &format!("{:>20}{}\n", if i == 0 { "OCR failed: " } else { "" }, line);
+            }
+        }
+        if verdict == Some(Verdict::Fail)
+            && let Some(expected) = self.answers.get(part)
+        {
+            for (i, line) in expected.lines().enumerate() {
+                text += &format!("{:>20}{}\n", if i == 0 { "expected: " } else { "" }, line);
+            }
+        }
+        self.emit(&text);
+    }
+}
+
+// reads input from path (or stdin if it is "-"), defaulting to input/YYYY/dayN.txt
+pub fn read_input(year: u32, day: u32, path: Option<&str>) -> Option<String> {
+    let filename = path.map_or_else(
+        || {
+            aoc::input_path(Path::new("input"), year, day)
+                .display()
+                .to_string()
+        },
+        String::from,
+    );
+    let result = if filename == "-" {
+        let mut input = String::new();
+        io::stdin().read_to_string(&mut input).map(|_| input)
+    } else {
+        read_file(&filename)
+    };
+    match result {
+        Ok(input) => Some(input),
+        Err(_) => {
+            eprintln!("{} day {:02}: Can't read {}", year, day, filename);
+            None
+        }
+    }
+}
+
+fn read_file(filename: &str) -> Result<String, std::io::Error> {
+    let mut f = std::fs::File::open(filename)?;
+    let mut input = String::new();
+    f.read_to_string(&mut input)?;
+    Ok(input)
+}
+
+#[test]
+fn test() {
+    let results = [
+        PartResult {
+            result: "1234".to_string(),
+            time: Duration::from_millis(500),
+            verdict: Some(Verdict::Pass),
+        },
+        PartResult {
+            result: "#..#\n####".to_string(),
+            time: Duration::from_millis(20),
+            verdict: None,
+        },
+    ];
+    assert_eq!(
+        "alice              0.01s                 1234   0.50s PASS              #..#...   0.02s\n",
+        table_row("alice", Duration::from_millis(10), &results)
+    );
+
+    let mut runner = Runner {
+        year: 2018,
+        day: 10,
+        output: Some(String::new()),
+        ..Default::default()
+    };
+    let art = Answer::Art("▛▜\n▙▟".to_string(), "no font is 4 pixels high".to_string());
+    runner.measure(2, || {
+        (
+            Outcome::Solved(art),
+            Duration::ZERO,
+            memory::Usage::default(),
+        )
+    });
+    assert_eq!(
+        Some(format!(
+            "2018 day 10 part 2: {:54} 0.00s\n{:20}▙▟\n        OCR failed: no font is 4 pixels high\n",
+            "▛▜", ""
+        )),
+        runner.output
+    );
+}
@@ -0,0 +1,171 @@
+// Creates the files for a new day's solution
+//
+// src/yYYYY/dayN.rs gets a template that compiles, `pub mod dayN;` is added to src/yYYYY.rs
+// (and `pub mod yYYYY;` to src/lib.rs for a new year), and an empty example is created in
+// examples/YYYY/dayN/.  build.rs then registers the solver on the next build.
+//
+// The empty example is skipped by its generated test (it has no expected answers) and by
+// --examples (it has no input) until it is filled in.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const TEMPLATE: &str = r#"use crate::day::Answer;
+
+crate::day::impl_day!(Vec<&'a str>);
+
+pub fn parse_input(input: &str) -> Vec<&str> {
+    input.lines().collect()
+}
+
+pub fn part1(_input: &[&str]) -> Answer {
+    Answer::Unimplemented
+}
+
+pub fn part2(_input: &[&str]) -> Answer {
+    Answer::Unimplemented
+}
+
+#[test]
+fn test() {
+    let test_input = "\
+";
+    let input = parse_input(test_input);
+    assert_eq!(Answer::Unimplemented, part1(&input));
+}
+"#;
+
+// Creates (or updates) the files for year/day under root, returning the paths touched
+pub fn new_day(root: &Path, year: u32, day: u32) -> Result<Vec<PathBuf>, String> {
+    let day_rs = root.join(format!("src/y{}/day{}.rs", year, day));
+    if day_rs.exists() {
+        return Err(format!("Already exists: {}", day_rs.display()));
+    }
+    let mut touched = vec![];
+
+    let lib_rs = root.join("src/lib.rs");
+    let lib = read(&lib_rs)?;
+    let year_mod = format!("pub mod y{};", year);
+    if !lib.lines().any(|line| line == year_mod) {
+        write(&lib_rs, &insert_sorted(&lib, &year_mod, is_year_mod))?;
+        touched.push(lib_rs);
+    }
+
+    let year_rs = root.join(format!("src/y{}.rs", year));
+    let contents = if year_rs.exists() {
+        read(&year_rs)?
+    } else {
+        String::new()
+    };
+    write(
+        &year_rs,
+        &insert_sorted(&contents, &format!("pub mod day{};", day), |line| {
+            line.starts_with("pub mod day")
+        }),
+    )?;
+    touched.push(year_rs);
+
+    create_dir(day_rs.parent().unwrap())?;
+    write(&day_rs, TEMPLATE)?;
+    touched.push(day_rs);
+
+    let examples = root.join(format!("examples/{}/day{}", year, day));
+    create_dir(&examples)?;
+    for file in ["example.txt", "example.answers"] {
+        let path = examples.join(file);
+        if !path.exists() {
+            write(&path, "")?;
+            touched.push(path);
+        }
+    }
+
+    Ok(touched)
+}
+
+// `pub mod y2019;` etc
+fn is_year_mod(line: &str) -> bool {
+    line.strip_prefix("pub mod y")
+        .and_then(|s| s.strip_suffix(';'))
+        .is_some_and(|year| year.parse::<u32>().is_ok())
+}
+
+// Inserts line among the lines matching is_peer, in the order rustfmt sorts them.
+// If there are no peers it is appended.
+fn insert_sorted(text: &str, line: &str, is_peer: impl Fn(&str) -> bool) -> String {
+    let mut lines: Vec<&str> = text.lines().collect();
+    let peers: Vec<usize> = (0..lines.len()).filter(|&i| is_peer(lines[i])).collect();
+    // compare names, not the `;` after them
+    let key = |line: &str| line.trim_end_matches(';').to_string();
+    let i = match peers.iter().find(|&&i| key(lines[i]) > key(line)) {
+        Some(&i) => i,
+        None => peers.last().map_or(lines.len(), |&i| i + 1),
+    };
+    lines.insert(i, line);
+    lines.join("\n") + "\n"
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), String> {
+    fs::write(path, contents).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+fn create_dir(path: &Path) -> Result<(), String> {
+    fs::create_dir_all(path).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+#[test]
+fn test() {
+    assert_eq!(
+        "pub mod day1;\npub mod day10;\npub mod day2;\npub mod day3;\n",
+        insert_sorted(
+            "pub mod day1;\npub mod day2;\npub mod day3;\n",
+            "pub mod day10;",
+            |line| line.starts_with("pub mod day")
+        )
+    );
+    assert_eq!(
+        "mod intcode;\n\npub mod day1;\npub mod day4;\n",
+        insert_sorted("mod intcode;\n\npub mod day1;\n", "pub mod day4;", |line| {
+            line.starts_with("pub mod day")
+        })
+    );
+    assert_eq!(
+        "pub mod grid;\n\npub mod y2015;\npub mod y2019;\npub mod y2021;\n\npub use y2019::intcode;\n",
+        insert_sorted(
+            "pub mod grid;\n\npub mod y2015;\npub mod y2021;\n\npub use y2019::intcode;\n",
+            "pub mod y2019;",
+            is_year_mod
+        )
+    );
+
+    let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+    fs::create_dir_all(root.join("src")).unwrap();
+    fs::write(root.join("src/lib.rs"), "pub mod grid;\n\npub mod y2015;\n").unwrap();
+    fs::write(root.join("src/y2015.rs"), "pub mod day1;\npub mod day3;\n").unwrap();
+
+    assert_eq!(4, new_day(&root, 2015, 2).unwrap().len());
+    assert_eq!(
+        "pub mod day1;\npub mod day2;\npub mod day3;\n",
+        fs::read_to_string(root.join("src/y2015.rs")).unwrap()
+    );
+    assert_eq!(
+        TEMPLATE,
+        fs::read_to_string(root.join("src/y2015/day2.rs")).unwrap()
+    );
+    assert!(root.join("examples/2015/day2/example.answers").exists());
+    assert!(new_day(&root, 2015, 2).is_err());
+
+    assert_eq!(5, new_day(&root, 2026, 1).unwrap().len());
+    assert_eq!(
+        "pub mod grid;\n\npub mod y2015;\npub mod y2026;\n",
+        fs::read_to_string(root.join("src/lib.rs")).unwrap()
+    );
+    assert_eq!(
+        "pub mod day1;\n",
+        fs::read_to_string(root.join("src/y2026.rs")).unwrap()
+    );
+    fs::remove_dir_all(&root).unwrap();
+}
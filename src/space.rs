@@ -0,0 +1,371 @@
+// Positions in 3 (or more) dimensions, and a sparse 3d grid
+//
+// Pos3 is the 3d equivalent of grid::Pos.  PosN<D> works for any number of dimensions (eg the
+// 4d version of a Conway cubes puzzle) at the cost of indexing by axis.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::ops::{Add, AddAssign, Sub, SubAssign};
+use std::str::FromStr;
+
+use crate::grid::Pos;
+
+// xyz triple used as index into a SparseGrid3
+#[derive(Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Pos3 {
+    pub x: i32,
+    pub y: i32,
+    pub z: i32,
+}
+
+pub const ORIGIN3: Pos3 = Pos3 { x: 0, y: 0, z: 0 };
+
+impl Pos3 {
+    // number of orientations accepted by rotate()
+    pub const ROTATIONS: u8 = 24;
+
+    pub fn new(x: i32, y: i32, z: i32) -> Self {
+        Self { x, y, z }
+    }
+
+    pub fn manhattan_distance(&self, other: &Self) -> u32 {
+        let diff = *other - *self;
+        diff.x.unsigned_abs() + diff.y.unsigned_abs() + diff.z.unsigned_abs()
+    }
+
+    // each coordinate replaced with -1, 0 or 1
+    pub fn signum(&self) -> Self {
+        Self::new(self.x.signum(), self.y.signum(), self.z.signum())
+    }
+
+    // the 6 positions sharing a face with this one
+    pub fn neighbours6(&self) -> impl Iterator<Item = Pos3> + use<> {
+        let p = *self;
+        [
+            (-1, 0, 0),
+            (1, 0, 0),
+            (0, -1, 0),
+            (0, 1, 0),
+            (0, 0, -1),
+            (0, 0, 1),
+        ]
+        .into_iter()
+        .map(move |d| p + d.into())
+    }
+
+    // the 26 positions sharing a face, edge or corner with this one
+    pub fn neighbours26(&self) -> impl Iterator<Item = Pos3> + use<> {
+        let p = *self;
+        (-1..=1)
+            .flat_map(|dz| (-1..=1).flat_map(move |dy| (-1..=1).map(move |dx| (dx, dy, dz))))
+            .filter(|&d| d != (0, 0, 0))
+            .map(move |d| p + d.into())
+    }
+
+    // one of the 24 orientations of a cube (facing along any of the 6 axes, then turned
+    // any of 4 ways around it) from:
+    // http://www.euclideanspace.com/maths/algebra/matrix/transforms/examples/index.htm
+    pub fn rotate(&self, rot: u8) -> Self {
+        let Pos3 { x, y, z } = *self;
+        match rot {
+            0 => Self::new(x, y, z),
+            1 => Self::new(x, z, -y),
+            2 => Self::new(x, -y, -z),
+            3 => Self::new(x, -z, y),
+            4 => Self::new(y, -x, z),
+            5 => Self::new(y, z, x),
+            6 => Self::new(y, x, -z),
+            7 => Self::new(y, -z, -x),
+            8 => Self::new(-x, -y, z),
+            9 => Self::new(-x, -z, -y),
+            10 => Self::new(-x, y, -z),
+            11 => Self::new(-x, z, y),
+            12 => Self::new(-y, x, z),
+            13 => Self::new(-y, -z, x),
+            14 => Self::new(-y, -x, -z),
+            15 => Self::new(-y, z, -x),
+            16 => Self::new(z, y, -x),
+            17 => Self::new(z, x, y),
+            18 => Self::new(z, -y, x),
+            19 => Self::new(z, -x, -y),
+            20 => Self::new(-z, -y, -x),
+            21 => Self::new(-z, -x, y),
+            22 => Self::new(-z, y, x),
+            23 => Self::new(-z, x, -y),
+            _ => panic!("rotate: invalid rotation {}", rot),
+        }
+    }
+}
+
+impl From<(i32, i32, i32)> for Pos3 {
+    fn from((x, y, z): (i32, i32, i32)) -> Self {
+        Self { x, y, z }
+    }
+}
+
+// "x,y,z"
+impl FromStr for Pos3 {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let coords = s
+            .split(',')
+            .map(|c| c.trim().parse())
+            .collect::<Result<Vec<i32>, _>>()
+            .map_err(|e| format!("{}: {}", s, e))?;
+        match coords[..] {
+            [x, y, z] => Ok(Self { x, y, z }),
+            _ => Err(format!("{}: expected 3 coordinates", s)),
+        }
+    }
+}
+
+impl fmt::Debug for Pos3 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl fmt::Display for Pos3 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({},{},{})", self.x, self.y, self.z)
+    }
+}
+
+impl Add for Pos3 {
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        Self {
+            x: self.x + other.x,
+            y: self.y + other.y,
+            z: self.z + other.z,
+        }
+    }
+}
+
+impl Sub for Pos3 {
+    type Output = Self;
+    fn sub(self, other: Self) -> Self {
+        Self {
+            x: self.x - other.x,
+            y: self.y - other.y,
+            z: self.z - other.z,
+        }
+    }
+}
+
+impl AddAssign for Pos3 {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for Pos3 {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+// position in D dimensions
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct PosN<const D: usize>(pub [i32; D]);
+
+impl<const D: usize> PosN<D> {
+    pub const ORIGIN: Self = PosN([0; D]);
+
+    pub fn manhattan_distance(&self, other: &Self) -> u32 {
+        self.0
+            .iter()
+            .zip(other.0.iter())
+            .map(|(a, b)| (a - b).unsigned_abs())
+            .sum()
+    }
+
+    // the 2 * D positions one step along a single axis
+    pub fn orthogonal_neighbours(&self) -> impl Iterator<Item = Self> + use<D> {
+        let p = *self;
+        (0..D).flat_map(move |axis| {
+            [-1, 1].map(|d| {
+                let mut q = p;
+                q.0[axis] += d;
+                q
+            })
+        })
+    }
+
+    // the 3^D - 1 positions no more than one step away along every axis
+    pub fn neighbours(&self) -> impl Iterator<Item = Self> + use<D> {
+        let p = *self;
+        (0..3usize.pow(D as u32)).filter_map(move |mut n| {
+            let mut q = p;
+            for c in q.0.iter_mut() {
+                *c += (n % 3) as i32 - 1;
+                n /= 3;
+            }
+            (q != p).then_some(q)
+        })
+    }
+}
+
+impl From<Pos> for PosN<2> {
+    fn from(p: Pos) -> Self {
+        PosN([p.x, p.y])
+    }
+}
+
+impl From<Pos3> for PosN<3> {
+    fn from(p: Pos3) -> Self {
+        PosN([p.x, p.y, p.z])
+    }
+}
+
+impl<const D: usize> fmt::Debug for PosN<D> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let coords: Vec<String> = self.0.iter().map(|c| c.to_string()).collect();
+        write!(f, "({})", coords.join(","))
+    }
+}
+
+impl<const D: usize> Add for PosN<D> {
+    type Output = Self;
+    fn add(mut self, other: Self) -> Self {
+        for (a, b) in self.0.iter_mut().zip(other.0) {
+            *a += b;
+        }
+        self
+    }
+}
+
+impl<const D: usize> Sub for PosN<D> {
+    type Output = Self;
+    fn sub(mut self, other: Self) -> Self {
+        for (a, b) in self.0.iter_mut().zip(other.0) {
+            *a -= b;
+        }
+        self
+    }
+}
+
+// 3d grid without fixed bounds, like grid::SparseGrid
+// reading a cell that hasn't been set returns default
+pub struct SparseGrid3<T> {
+    min: Pos3, // lowest x, y and z set
+    max: Pos3, // highest x, y and z set
+    default: T,
+    data: HashMap<Pos3, T>,
+}
+
+impl<T> SparseGrid3<T>
+where
+    T: Clone,
+{
+    pub fn new(default: T) -> Self {
+        Self {
+            min: ORIGIN3,
+            max: ORIGIN3,
+            default,
+            data: HashMap::new(),
+        }
+    }
+
+    pub fn get(&self, p: Pos3) -> &T {
+        self.data.get(&p).unwrap_or(&self.default)
+    }
+
+    pub fn set(&mut self, p: Pos3, v: T) {
+        if self.data.is_empty() {
+            self.min = p;
+            self.max = p;
+        } else {
+            self.min = Pos3::new(
+                self.min.x.min(p.x),
+                self.min.y.min(p.y),
+                self.min.z.min(p.z),
+            );
+            self.max = Pos3::new(
+                self.max.x.max(p.x),
+                self.max.y.max(p.y),
+                self.max.z.max(p.z),
+            );
+        }
+        self.data.insert(p, v);
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&Pos3, &T)> {
+        self.data.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    // corners of the box containing every cell that has been set (origin if empty)
+    pub fn min(&self) -> Pos3 {
+        self.min
+    }
+
+    pub fn max(&self) -> Pos3 {
+        self.max
+    }
+
+    // neighbouring (position, value) sharing a face, or a face, edge or corner
+    pub fn neighbours6(&self, p: Pos3) -> impl Iterator<Item = (Pos3, &T)> {
+        p.neighbours6().map(|p| (p, self.get(p)))
+    }
+
+    pub fn neighbours26(&self, p: Pos3) -> impl Iterator<Item = (Pos3, &T)> {
+        p.neighbours26().map(|p| (p, self.get(p)))
+    }
+}
+
+#[test]
+fn test() {
+    let a = Pos3::new(1, -2, 3);
+    let b: Pos3 = "4, 5,-6".parse().unwrap();
+    assert_eq!(Pos3::new(5, 3, -3), a + b);
+    assert_eq!(Pos3::new(3, 7, -9), b - a);
+    assert_eq!(19, a.manhattan_distance(&b));
+    assert_eq!(Pos3::new(1, -1, 1), a.signum());
+    assert_eq!("(1,-2,3)", format!("{:?}", a));
+    assert!("1,2".parse::<Pos3>().is_err());
+    assert!("1,x,2".parse::<Pos3>().is_err());
+
+    assert_eq!(6, a.neighbours6().count());
+    assert!(a.neighbours6().all(|n| n.manhattan_distance(&a) == 1));
+    assert_eq!(26, a.neighbours26().count());
+    assert_eq!(Some(Pos3::new(0, -3, 2)), a.neighbours26().next());
+    let mut rotations: Vec<Pos3> = (0..Pos3::ROTATIONS).map(|r| a.rotate(r)).collect();
+    rotations.sort_by_key(|p| (p.x, p.y, p.z));
+    rotations.dedup();
+    assert_eq!(24, rotations.len());
+
+    let p = PosN([1, 2, 3, 4]);
+    assert_eq!(80, p.neighbours().count());
+    assert_eq!(8, p.orthogonal_neighbours().count());
+    assert_eq!(10, p.manhattan_distance(&PosN::ORIGIN));
+    assert_eq!(PosN([2, 4, 6, 8]), p + p);
+    assert_eq!(PosN::ORIGIN, p - p);
+    assert_eq!(PosN([1, -2, 3]), PosN::from(a));
+    assert_eq!("(1,2,3,4)", format!("{:?}", p));
+
+    let mut grid = SparseGrid3::new('.');
+    assert!(grid.is_empty());
+    grid.set(a, '#');
+    grid.set(b, '#');
+    assert_eq!(2, grid.len());
+    assert_eq!(&'#', grid.get(a));
+    assert_eq!(&'.', grid.get(ORIGIN3));
+    assert_eq!(Pos3::new(1, -2, -6), grid.min());
+    assert_eq!(Pos3::new(4, 5, 3), grid.max());
+    assert_eq!(
+        1,
+        grid.neighbours26(a + Pos3::new(1, 1, 1))
+            .filter(|(_, c)| **c == '#')
+            .count()
+    );
+    assert_eq!(0, grid.neighbours6(a).filter(|(_, c)| **c == '#').count());
+}
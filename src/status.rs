@@ -0,0 +1,135 @@
+// Progress report: a calendar per year showing how far each day has got
+//
+// A day is missing until it has a registered solver, then gains its input and finally the
+// answers recorded in answers/YYYY/dayN.txt.  The last day of each year has no part 2.
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DayStatus {
+    pub released: bool,
+    pub implemented: bool,
+    pub input: bool,
+    pub answered: [bool; 2],
+    pub part2_na: bool,
+}
+
+impl DayStatus {
+    fn symbol(&self) -> &'static str {
+        match self {
+            DayStatus {
+                released: false, ..
+            } => "",
+            DayStatus {
+                implemented: false, ..
+            } => ".",
+            DayStatus {
+                answered: [true, true],
+                ..
+            } => "**",
+            DayStatus {
+                answered: [true, false],
+                part2_na: true,
+                ..
+            } => "*-",
+            DayStatus {
+                answered: [true, false],
+                ..
+            } => "*",
+            DayStatus { input: true, .. } => "i",
+            _ => "s",
+        }
+    }
+
+    // parts that have an answer to verify
+    fn parts(&self) -> usize {
+        match (self.released, self.part2_na) {
+            (false, _) => 0,
+            (true, true) => 1,
+            (true, false) => 2,
+        }
+    }
+}
+
+pub const LEGEND: &str = "** both parts verified   * part 1 verified   *- part 1 verified, no part 2\n\
+                          i  input present   s  implemented   .  missing";
+
+// eg "25/25 implemented, 49/49 parts verified"
+fn summary(days: &[DayStatus]) -> String {
+    format!(
+        "{}/{} implemented, {}/{} parts verified",
+        days.iter().filter(|d| d.released && d.implemented).count(),
+        days.iter().filter(|d| d.released).count(),
+        days.iter()
+            .filter(|d| d.released && d.implemented)
+            .map(|d| d.answered.iter().filter(|&&a| a).count())
+            .sum::<usize>(),
+        days.iter().map(|d| d.parts()).sum::<usize>()
+    )
+}
+
+// the year's days (days[0] is day 1) as a calendar, five days to a row
+pub fn calendar(year: u32, days: &[DayStatus]) -> String {
+    let mut s = format!("{}  {}\n", year, summary(days));
+    for (row, week) in days.chunks(5).enumerate() {
+        for (i, status) in week.iter().enumerate() {
+            s += &format!("{:>4} {:2}", row * 5 + i + 1, status.symbol());
+        }
+        s = s.trim_end().to_string() + "\n";
+    }
+    s
+}
+
+// the same calendar as a Markdown table
+pub fn markdown(year: u32, days: &[DayStatus]) -> String {
+    let mut s = format!("## {}\n\n{}\n\n", year, summary(days));
+    s += "| | | | | |\n|---|---|---|---|---|\n";
+    for (row, week) in days.chunks(5).enumerate() {
+        s += "|";
+        for (i, status) in week.iter().enumerate() {
+            s += &format!(
+                " {} {} |",
+                row * 5 + i + 1,
+                status.symbol().replace('*', "\\*")
+            );
+        }
+        s += "\n";
+    }
+    s
+}
+
+#[test]
+fn test() {
+    let done = DayStatus {
+        released: true,
+        implemented: true,
+        input: true,
+        answered: [true, true],
+        part2_na: false,
+    };
+    let mut days = vec![done; 7];
+    days[1].answered = [true, false];
+    days[2].answered = [false, false];
+    days[3] = DayStatus {
+        released: true,
+        ..Default::default()
+    };
+    days[4] = DayStatus {
+        input: false,
+        ..days[2]
+    };
+    days[5].part2_na = true;
+    days[5].answered = [true, false];
+    days[6].released = false;
+    assert_eq!(
+        "2019  5/6 implemented, 4/11 parts verified\n   \
+            1 **   2 *    3 i    4 .    5 s\n   \
+            6 *-   7\n",
+        calendar(2019, &days)
+    );
+    assert_eq!(
+        "## 2019\n\n5/6 implemented, 4/11 parts verified\n\n\
+            | | | | | |\n|---|---|---|---|---|\n\
+            | 1 \\*\\* | 2 \\* | 3 i | 4 . | 5 s |\n\
+            | 6 \\*- | 7  |\n",
+        markdown(2019, &days)
+    );
+}
@@ -0,0 +1,161 @@
+// Ledger of answers submitted to adventofcode.com and how they were judged
+//
+// Each submission that the site gave a verdict on is appended to submissions.jsonl.  Before
+// submitting, the ledger is used to refuse answers that are already known to be wrong, either
+// because that exact value was rejected or because it is outside a too high/too low bound.
+
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+
+use serde_json::{Value, json};
+
+use crate::aoc::Reply;
+
+pub const FILENAME: &str = "submissions.jsonl";
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Submission {
+    pub year: u32,
+    pub day: u32,
+    pub part: u8,
+    pub answer: String,
+    pub reply: Reply,
+}
+
+impl Submission {
+    fn to_json(&self) -> Value {
+        json!({
+            "year": self.year,
+            "day": self.day,
+            "part": self.part,
+            "answer": self.answer,
+            "reply": self.reply.label(),
+            "timestamp": chrono::Local::now().to_rfc3339(),
+        })
+    }
+
+    fn from_json(v: &Value) -> Option<Self> {
+        Some(Self {
+            year: v["year"].as_u64()? as u32,
+            day: v["day"].as_u64()? as u32,
+            part: v["part"].as_u64()? as u8,
+            answer: v["answer"].as_str()?.to_string(),
+            reply: Reply::from_label(v["reply"].as_str()?)?,
+        })
+    }
+}
+
+#[derive(Default)]
+pub struct Ledger {
+    submissions: Vec<Submission>,
+}
+
+impl Ledger {
+    pub fn load() -> Self {
+        Self::parse(&fs::read_to_string(FILENAME).unwrap_or_default())
+    }
+
+    fn parse(s: &str) -> Self {
+        Self {
+            submissions: s
+                .lines()
+                .filter_map(|line| Submission::from_json(&serde_json::from_str(line).ok()?))
+                .collect(),
+        }
+    }
+
+    // adds a submission to the ledger file, if the reply says anything about the answer
+    pub fn record(&mut self, submission: Submission) -> std::io::Result<()> {
+        if submission.reply.label().is_none() {
+            return Ok(());
+        }
+        let mut f = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(FILENAME)?;
+        writeln!(f, "{}", submission.to_json())?;
+        self.submissions.push(submission);
+        Ok(())
+    }
+
+    // reason not to submit answer, if the ledger shows it can't be right
+    pub fn check(&self, year: u32, day: u32, part: u8, answer: &str) -> Result<(), String> {
+        let value: Option<i128> = answer.parse().ok();
+        for s in self
+            .submissions
+            .iter()
+            .filter(|s| s.year == year && s.day == day && s.part == part)
+        {
+            let bound: Option<i128> = s.answer.parse().ok();
+            match s.reply {
+                Reply::Correct if s.answer == answer => {
+                    return Err(format!("{} was already accepted", answer));
+                }
+                Reply::Correct => {
+                    return Err(format!("already solved, the answer was {}", s.answer));
+                }
+                _ if s.answer == answer => {
+                    return Err(format!("{} was already rejected ({})", answer, s.reply));
+                }
+                Reply::TooHigh if value.zip(bound).is_some_and(|(v, b)| v >= b) => {
+                    return Err(format!(
+                        "{} is too high ({} was too high)",
+                        answer, s.answer
+                    ));
+                }
+                Reply::TooLow if value.zip(bound).is_some_and(|(v, b)| v <= b) => {
+                    return Err(format!("{} is too low ({} was too low)", answer, s.answer));
+                }
+                _ => (),
+            }
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn test() {
+    let submission = |answer: &str, reply| Submission {
+        year: 2021,
+        day: 5,
+        part: 1,
+        answer: answer.to_string(),
+        reply,
+    };
+    let submissions = vec![
+        submission("1000", Reply::TooHigh),
+        submission("200", Reply::TooLow),
+        submission("500", Reply::Wrong),
+    ];
+    let ledger = Ledger::parse(
+        &submissions
+            .iter()
+            .map(|s| s.to_json().to_string() + "\n")
+            .collect::<String>(),
+    );
+    assert_eq!(submissions, ledger.submissions);
+
+    assert_eq!(Ok(()), ledger.check(2021, 5, 1, "600"));
+    assert_eq!(Ok(()), ledger.check(2021, 5, 2, "500"));
+    assert_eq!(Ok(()), ledger.check(2021, 5, 1, "abc"));
+    assert_eq!(
+        Err("500 was already rejected (wrong)".to_string()),
+        ledger.check(2021, 5, 1, "500")
+    );
+    assert_eq!(
+        Err("1200 is too high (1000 was too high)".to_string()),
+        ledger.check(2021, 5, 1, "1200")
+    );
+    assert_eq!(
+        Err("-5 is too low (200 was too low)".to_string()),
+        ledger.check(2021, 5, 1, "-5")
+    );
+
+    let ledger = Ledger {
+        submissions: vec![submission("600", Reply::Correct)],
+    };
+    assert_eq!(
+        Err("already solved, the answer was 600".to_string()),
+        ledger.check(2021, 5, 1, "601")
+    );
+}
@@ -1,3 +1,5 @@
+crate::day::impl_day!(Vec<char>);
+
 pub fn part1(directions: &[char]) -> i32 {
     lift(directions)
 }
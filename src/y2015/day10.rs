@@ -1,3 +1,5 @@
+crate::day::impl_day!(Vec<u8>);
+
 pub fn part1(input: &[u8]) -> usize {
     expand(input, 40)
 }
@@ -1,3 +1,5 @@
+crate::day::impl_day!(Password);
+
 pub fn part1(password: &Password) -> String {
     password_to_s(&next_password(password))
 }
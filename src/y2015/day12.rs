@@ -1,5 +1,7 @@
 use serde_json::Value;
 
+crate::day::impl_day!(Value);
+
 pub fn part1(json: &Value) -> i64 {
     sum(json, "")
 }
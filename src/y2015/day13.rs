@@ -4,6 +4,8 @@ use regex::Regex;
 use std::collections::HashMap;
 use std::fmt;
 
+crate::day::impl_day!(Diners);
+
 pub fn part1(diners: &Diners) -> i32 {
     diners.happiest()
 }
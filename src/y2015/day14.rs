@@ -1,6 +1,8 @@
 use lazy_static::lazy_static;
 use regex::Regex;
 
+crate::day::impl_day!(Vec<Reindeer>);
+
 pub fn part1(reindeer: &[Reindeer]) -> u32 {
     solve_part1(reindeer, 2503)
 }
@@ -1,5 +1,7 @@
 use std::collections::HashMap;
 
+crate::day::impl_day!(Vec<Ingredient>);
+
 pub fn part1(ingredients: &[Ingredient]) -> i32 {
     best_score(ingredients, None)
 }
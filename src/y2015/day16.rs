@@ -1,3 +1,5 @@
+crate::day::impl_day!(Vec<Aunt>);
+
 pub fn part1(input: &[Aunt]) -> u32 {
     for aunt in input {
         if aunt.items.iter().all(|(item, value)| match item.as_str() {
@@ -1,5 +1,7 @@
 use std::collections::HashMap;
 
+crate::day::impl_day!(Stats);
+
 pub fn part1(stats: &Stats) -> u32 {
     stats.total_combinations()
 }
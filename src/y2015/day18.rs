@@ -1,5 +1,7 @@
 use std::fmt;
 
+crate::day::impl_day!(Grid);
+
 pub fn part1(grid: &Grid) -> u32 {
     grid.step(100).count()
 }
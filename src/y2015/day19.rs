@@ -1,5 +1,7 @@
 use std::collections::HashSet;
 
+crate::day::impl_day!(Input);
+
 pub struct Input {
     replacements: Vec<Replacement>,
     molecule: String,
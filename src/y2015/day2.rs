@@ -1,3 +1,5 @@
+crate::day::impl_day!(Vec<Present>);
+
 pub fn part1(presents: &[Present]) -> u32 {
     total_paper(presents)
 }
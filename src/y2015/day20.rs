@@ -1,3 +1,5 @@
+crate::day::impl_day!(u32);
+
 pub fn part1(min_presents: &u32) -> u32 {
     let mut house = 1;
     loop {
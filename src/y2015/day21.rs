@@ -1,5 +1,7 @@
 use itertools::Itertools;
 
+crate::day::impl_day!((i32, i32));
+
 pub fn part1(result: &(i32, i32)) -> i32 {
     result.0
 }
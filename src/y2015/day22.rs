@@ -1,6 +1,8 @@
 use std::cmp::Ordering;
 use std::collections::BinaryHeap;
 
+crate::day::impl_day!(GameState);
+
 pub fn part1(state: &GameState) -> i32 {
     find_cheapest_mana_win(state).unwrap()
 }
@@ -1,3 +1,5 @@
+crate::day::impl_day!(Vec<Instruction>);
+
 pub fn part1(program: &[Instruction]) -> u64 {
     let mut vm = VM::new();
     vm.execute(program);
@@ -1,3 +1,5 @@
+crate::day::impl_day!(Vec<u32>);
+
 pub fn part1(weights: &[u32]) -> u64 {
     find_group1(weights, 3).quantum_entanglement(weights)
 }
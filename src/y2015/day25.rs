@@ -1,11 +1,15 @@
 use regex::Regex;
 
+use crate::day::Answer;
+
+crate::day::impl_day!((u32, u32));
+
 pub fn part1(input: &(u32, u32)) -> u64 {
     calc_code(input.0, input.1)
 }
 
-pub fn part2(_input: &(u32, u32)) -> &str {
-    "n/a"
+pub fn part2(_input: &(u32, u32)) -> Answer {
+    Answer::NotApplicable
 }
 
 fn calc_code(row: u32, col: u32) -> u64 {
@@ -1,5 +1,7 @@
 use std::collections::HashSet;
 
+crate::day::impl_day!(Vec<Dir>);
+
 #[derive(Debug)]
 pub enum Dir {
     North,
@@ -2,6 +2,8 @@ use md5::{Digest, Md5};
 use std::sync::mpsc;
 use std::thread;
 
+crate::day::impl_day!(String);
+
 pub fn parse_input(input: &str) -> String {
     input.lines().next().unwrap().to_string()
 }
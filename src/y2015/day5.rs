@@ -1,3 +1,5 @@
+crate::day::impl_day!(Vec<String>);
+
 fn count_vowels(chars: &[char]) -> usize {
     chars
         .iter()
@@ -3,6 +3,8 @@ use regex::Regex;
 use std::collections::HashMap;
 use std::collections::HashSet;
 
+crate::day::impl_day!(Vec<Instruction>);
+
 #[derive(Debug)]
 enum Action {
     TurnOn,
@@ -2,6 +2,8 @@ use lazy_static::lazy_static;
 use regex::Regex;
 use std::collections::HashMap;
 
+crate::day::impl_day!(HashMap<String, Wire>);
+
 pub fn part1(wires: &HashMap<String, Wire>) -> u16 {
     measure_a(wires)
 }
@@ -1,3 +1,5 @@
+crate::day::impl_day!(Vec<&'a [u8]>);
+
 pub fn parse_input(input: &str) -> Vec<&[u8]> {
     input.lines().map(|s| s.as_bytes()).collect()
 }
@@ -2,6 +2,8 @@ use itertools::Itertools;
 use std::collections::HashMap;
 use std::fmt;
 
+crate::day::impl_day!((u32, u32));
+
 pub fn part1(minmax: &(u32, u32)) -> u32 {
     minmax.0
 }
@@ -1,6 +1,8 @@
 use crate::grid::{Compass, ORIGIN, Pos};
 use std::collections::HashSet;
 
+crate::day::impl_day!(Vec<Instruction>);
+
 pub fn part1(input: &[Instruction]) -> u32 {
     let mut state = State::new();
     for instruction in input {
@@ -1,3 +1,5 @@
+crate::day::impl_day!(Vec<Vec<Direction>>);
+
 pub fn part1(input: &[Vec<Direction>]) -> String {
     solve(input, normal_keypad)
 }
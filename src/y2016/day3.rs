@@ -1,3 +1,5 @@
+crate::day::impl_day!(Vec<Vec<u32>>);
+
 pub fn part1(input: &[Vec<u32>]) -> usize {
     input.iter().filter(|triangle| possible(triangle)).count()
 }
@@ -1,3 +1,5 @@
+crate::day::impl_day!(Vec<Room>);
+
 // sum of the sector IDs of the real rooms
 pub fn part1(input: &[Room]) -> u32 {
     input
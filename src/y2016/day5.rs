@@ -1,5 +1,7 @@
 use md5::{Digest, Md5};
 
+crate::day::impl_day!(String);
+
 pub fn parse_input(input: &str) -> String {
     input.trim().to_string()
 }
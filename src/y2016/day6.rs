@@ -1,3 +1,5 @@
+crate::day::impl_day!(Vec<String>);
+
 pub fn part1(codes: &[String]) -> String {
     most_common_letters(codes)
 }
@@ -1,3 +1,5 @@
+crate::day::impl_day!(Vec<IPv7>);
+
 pub struct IPv7 {
     address: Vec<u8>,
     // boundary markers (start, end + 1) pairs of indexes into address
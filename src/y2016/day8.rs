@@ -1,6 +1,9 @@
+use crate::day::Answer;
+use crate::ocr;
 use crate::pixel_buffer::PixelBuffer;
 use std::collections::VecDeque;
-use std::fmt;
+
+crate::day::impl_day!(Vec<Instruction>);
 
 struct Screen {
     pixels: Vec<VecDeque<bool>>,
@@ -34,13 +37,7 @@ impl Screen {
         }
     }
 
-    fn count_lit(&self) -> usize {
-        self.pixels.iter().flatten().filter(|&&pixel| pixel).count()
-    }
-}
-
-impl fmt::Display for Screen {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    fn to_pixel_buffer(&self) -> PixelBuffer {
         let mut buffer = PixelBuffer::new(
             self.pixels.first().unwrap().len() as u32,
             self.pixels.len() as u32,
@@ -50,7 +47,11 @@ impl fmt::Display for Screen {
                 buffer.set(x as u32, y as u32, pixel);
             }
         }
-        write!(f, "{}", buffer)
+        buffer
+    }
+
+    fn count_lit(&self) -> usize {
+        self.pixels.iter().flatten().filter(|&&pixel| pixel).count()
     }
 }
 
@@ -65,8 +66,8 @@ pub fn part1(input: &[Instruction]) -> usize {
     process(input).count_lit()
 }
 
-pub fn part2(input: &[Instruction]) -> String {
-    process(input).to_string()
+pub fn part2(input: &[Instruction]) -> Answer {
+    ocr::answer(&process(input).to_pixel_buffer())
 }
 
 fn process(instructions: &[Instruction]) -> Screen {
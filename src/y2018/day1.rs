@@ -1,5 +1,7 @@
 use std::collections::HashSet;
 
+crate::day::impl_day!(Vec<i32>);
+
 pub fn part1(input: &[i32]) -> i32 {
     sum_lines(input)
 }
@@ -1,11 +1,15 @@
 use regex::Regex;
 
+use crate::day::Answer;
+use crate::ocr;
 use crate::pixel_buffer::PixelBuffer;
 
-pub fn part1(stars: &[Star]) -> String {
+crate::day::impl_day!(Vec<Star>);
+
+pub fn part1(stars: &[Star]) -> Answer {
     let mut stars = stars.to_owned();
     find_message(&mut stars);
-    to_pixel_buffer(&stars).to_string()
+    ocr::answer(&to_pixel_buffer(&stars))
 }
 
 pub fn part2(stars: &[Star]) -> i32 {
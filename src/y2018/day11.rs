@@ -1,5 +1,7 @@
 use std::cmp;
 
+crate::day::impl_day!(usize);
+
 pub fn parse_input(input: &str) -> usize {
     input.trim().parse().unwrap()
 }
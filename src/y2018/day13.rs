@@ -1,5 +1,8 @@
+use crate::grid::{Grid, Pos};
+use crate::render::{Render, View};
 use std::fmt;
-use std::fmt::Write;
+
+crate::day::impl_day!(Mine);
 
 pub fn part1(mine: &Mine) -> String {
     format!("{:?}", mine.clone().first_crash())
@@ -19,7 +22,7 @@ enum Cell {
     Intersection,
 }
 
-impl Cell {
+impl Render for Cell {
     fn to_char(&self) -> char {
         match &self {
             Cell::Empty => ' ',
@@ -110,7 +113,7 @@ impl Cart {
         }
     }
 
-    fn move_turn(&mut self, map: &[Vec<Cell>]) {
+    fn move_turn(&mut self, map: &Grid<Cell>) {
         match self.facing {
             Direction::North => self.y -= 1,
             Direction::East => self.x += 1,
@@ -118,7 +121,7 @@ impl Cart {
             Direction::West => self.x -= 1,
         }
 
-        match map[self.y][self.x] {
+        match map.get(Pos::from((self.x, self.y))) {
             Cell::Corner1 => match self.facing {
                 Direction::North => self.facing = Direction::East,
                 Direction::East => self.facing = Direction::North,
@@ -139,25 +142,17 @@ impl Cart {
 
 #[derive(Clone)]
 pub struct Mine {
-    map: Vec<Vec<Cell>>,
+    map: Grid<Cell>,
     carts: Vec<Cart>, // kept in order
 }
 
 impl fmt::Debug for Mine {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let mut i = 0;
-        for (y, row) in self.map.iter().enumerate() {
-            for (x, cell) in row.iter().enumerate() {
-                if i < self.carts.len() && self.carts[i].y == y && self.carts[i].x == x {
-                    f.write_char(self.carts[i].facing.to_char()).unwrap();
-                    i += 1;
-                } else {
-                    f.write_char(cell.to_char()).unwrap();
-                }
-            }
-            f.write_char('\n').unwrap();
+        let mut view = View::new(&self.map);
+        for cart in &self.carts {
+            view = view.overlay(Pos::from((cart.x, cart.y)), cart.facing.to_char());
         }
-        Ok(())
+        write!(f, "{}", view)
     }
 }
 
@@ -216,24 +211,26 @@ impl Mine {
 }
 
 pub fn parse_input(input: &str) -> Mine {
+    let width = input.lines().map(|line| line.len()).max().unwrap_or(0);
+    let height = input.lines().count();
     let mut mine = Mine {
-        map: vec![],
+        map: Grid::new(width as u32, height as u32, Cell::Empty),
         carts: vec![],
     };
     for (y, line) in input.lines().enumerate() {
-        mine.map.push(vec![Cell::Empty; line.len()]);
         for (x, c) in line.chars().enumerate() {
+            let p = Pos::from((x, y));
             if c == ' ' {
-                mine.map[y][x] = Cell::Empty;
+                mine.map.set(p, Cell::Empty);
             } else if c == '/' {
-                mine.map[y][x] = Cell::Corner1;
+                mine.map.set(p, Cell::Corner1);
             } else if c == '\\' {
-                mine.map[y][x] = Cell::Corner2;
+                mine.map.set(p, Cell::Corner2);
             } else if c == '^' || c == 'v' || c == '<' || c == '>' {
                 if c == '^' || c == 'v' {
-                    mine.map[y][x] = Cell::NS;
+                    mine.map.set(p, Cell::NS);
                 } else {
-                    mine.map[y][x] = Cell::EW;
+                    mine.map.set(p, Cell::EW);
                 }
                 mine.carts.push(Cart {
                     x,
@@ -243,11 +240,11 @@ pub fn parse_input(input: &str) -> Mine {
                     destroyed: false,
                 });
             } else if c == '-' {
-                mine.map[y][x] = Cell::EW;
+                mine.map.set(p, Cell::EW);
             } else if c == '|' {
-                mine.map[y][x] = Cell::NS;
+                mine.map.set(p, Cell::NS);
             } else if c == '+' {
-                mine.map[y][x] = Cell::Intersection;
+                mine.map.set(p, Cell::Intersection);
             }
         }
     }
@@ -1,3 +1,5 @@
+crate::day::impl_day!(&'a str);
+
 pub fn parse_input(input: &str) -> &str {
     input.trim()
 }
@@ -1,10 +1,13 @@
 use crate::grid::Compass::*;
 use crate::grid::{Compass, Grid, Pos};
+use crate::options::RunOptions;
+use crate::render::{Colour, Render, View};
 use std::cmp::Ordering;
 use std::collections::HashSet;
 use std::fmt;
-use std::fmt::Write;
-use std::slice::Iter;
+use std::io::{self, IsTerminal};
+
+crate::day::impl_day!(&'a str);
 
 pub fn parse_input(input: &str) -> &str {
     input
@@ -35,6 +38,13 @@ impl Team {
         }
     }
 
+    fn colour(&self) -> Colour {
+        match &self {
+            Team::Elves => Colour::Green,
+            Team::Goblins => Colour::Red,
+        }
+    }
+
     fn other(&self) -> Team {
         match &self {
             Team::Elves => Team::Goblins,
@@ -88,6 +98,15 @@ enum Tile {
     Wall,
 }
 
+impl Render for Tile {
+    fn to_char(&self) -> char {
+        match self {
+            Tile::Open => '.',
+            Tile::Wall => '#',
+        }
+    }
+}
+
 struct Game {
     grid: Grid<Tile>,
     units: Vec<Unit>,
@@ -98,11 +117,6 @@ struct Game {
 }
 
 // Order is important for derived ordering (NWES is reading order)
-fn each_dir() -> Iter<'static, Compass> {
-    static DIRS: [Compass; 4] = [North, West, East, South];
-    DIRS.iter()
-}
-
 #[derive(Debug)]
 struct Path {
     start_dir: Compass,
@@ -133,7 +147,7 @@ impl Game {
             grid,
             units,
             rounds: 0,
-            debug: false,
+            debug: RunOptions::get().debug,
             elf_power: 3,
             winner: None,
         }
@@ -156,7 +170,11 @@ impl Game {
     fn simulate(&mut self, require_total_victory: Option<Team>) {
         loop {
             if self.debug {
-                print!("After {} round(s)\n{:?}", self.rounds, self);
+                print!(
+                    "After {} round(s)\n{}",
+                    self.rounds,
+                    self.view().colour(io::stdout().is_terminal())
+                );
             }
             for u in 0..self.units.len() {
                 if self.is_victory(&self.units[u].team) {
@@ -221,8 +239,8 @@ impl Game {
             depth += 1;
             let mut new_paths: Vec<Path> = vec![];
             for old_path in paths.into_iter() {
-                for dir in each_dir() {
-                    let p = old_path.p.step(*dir);
+                for dir in Compass::CARDINALS {
+                    let p = old_path.p.step(dir);
                     if seen.contains(&p) {
                         continue;
                     }
@@ -233,7 +251,7 @@ impl Game {
                             stop = true;
                         }
                         new_paths.push(Path {
-                            start_dir: if depth == 1 { *dir } else { old_path.start_dir },
+                            start_dir: if depth == 1 { dir } else { old_path.start_dir },
                             p,
                             inrange,
                         });
@@ -286,7 +304,7 @@ impl Game {
             return;
         }
         let other_team = unit.team.other();
-        let neighbours: Vec<Pos> = each_dir().map(|dir| unit.p.step(*dir)).collect();
+        let neighbours = Compass::CARDINALS.map(|dir| unit.p.step(dir));
         let mut enemies: Vec<(usize, &mut Unit)> = self
             .units
             .iter_mut()
@@ -373,36 +391,23 @@ impl Game {
     }
 }
 
+impl Game {
+    // the grid with each unit drawn on it, and their hit points after each row
+    fn view(&self) -> View<'_, Grid<Tile>> {
+        let mut view = View::new(&self.grid);
+        for (u, unit) in self.units.iter().enumerate() {
+            let c = unit.team.to_char();
+            view = view
+                .overlay_coloured(unit.p, c, unit.team.colour())
+                .note(unit.p.y, &format!(" {} {}({})", u, c, unit.hp));
+        }
+        view
+    }
+}
+
 impl fmt::Debug for Game {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let mut u = 0;
-        let mut annot = "".to_string();
-        for (p, tile) in self.grid.iter() {
-            if u < self.units.len() && self.units[u].p == p {
-                // a unit is in this position
-                f.write_char(self.units[u].team.to_char())?;
-                annot.push_str(&format!(
-                    " {} {}({})",
-                    u,
-                    self.units[u].team.to_char(),
-                    self.units[u].hp
-                ));
-                u += 1;
-            } else {
-                f.write_char(match tile {
-                    Tile::Open => '.',
-                    Tile::Wall => '#',
-                })?;
-            }
-            if p.x >= self.grid.maxx() {
-                if !annot.is_empty() {
-                    f.write_str(&annot)?;
-                    annot.clear();
-                }
-                f.write_char('\n')?;
-            }
-        }
-        Ok(())
+        write!(f, "{}", self.view())
     }
 }
 
@@ -2,6 +2,8 @@ use self::Op::*;
 use std::collections::HashSet;
 use std::slice::Iter;
 
+crate::day::impl_day!(Input);
+
 pub struct Input {
     samples: Vec<Sample>,
     program: Vec<Inst>,
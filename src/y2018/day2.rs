@@ -1,5 +1,7 @@
 use std::collections::HashMap;
 
+crate::day::impl_day!(Vec<String>);
+
 pub fn parse_input(input: &str) -> Vec<String> {
     input.lines().map(|s| s.to_owned()).collect()
 }
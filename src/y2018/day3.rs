@@ -2,6 +2,8 @@ use regex::Regex;
 use std::collections::HashMap;
 use std::collections::hash_map::Entry;
 
+crate::day::impl_day!(Vec<Claim>);
+
 pub fn part1(claims: &[Claim]) -> i32 {
     let mut fabric = HashMap::new();
     process(&mut fabric, claims)
@@ -1,6 +1,8 @@
 use chrono::prelude::*;
 use std::collections::HashMap;
 
+crate::day::impl_day!(Stats);
+
 pub type Stats = HashMap<i32, (i32, [i32; 60])>;
 
 pub fn parse_input(input: &str) -> Stats {
@@ -1,6 +1,8 @@
 use std::collections::VecDeque;
 use std::iter::FromIterator;
 
+crate::day::impl_day!(&'a str);
+
 pub fn parse_input(input: &str) -> &str {
     input
 }
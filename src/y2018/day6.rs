@@ -2,6 +2,8 @@ use crate::grid::Pos;
 use std::collections::HashMap;
 use std::collections::hash_map::Entry;
 
+crate::day::impl_day!(Vec<Pos>);
+
 pub fn part1(points: &[Pos]) -> usize {
     largest_finite_area(points)
 }
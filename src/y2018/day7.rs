@@ -1,6 +1,8 @@
 use petgraph::Incoming;
 use petgraph::graphmap::DiGraphMap;
 
+crate::day::impl_day!(DiGraphMap<char, ()>);
+
 pub fn part1(graph: &DiGraphMap<char, ()>) -> String {
     get_order(graph.clone())
 }
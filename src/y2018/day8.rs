@@ -1,3 +1,5 @@
+crate::day::impl_day!(Vec<usize>);
+
 pub fn part1(input: &[usize]) -> usize {
     metadata_sum(input)
 }
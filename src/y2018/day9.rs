@@ -1,5 +1,7 @@
 use std::collections::VecDeque;
 
+crate::day::impl_day!(Vec<usize>);
+
 pub fn part1(input: &[usize]) -> usize {
     simulate_game(input[0], input[1])
 }
@@ -1,4 +1,4 @@
-mod intcode;
+pub mod intcode;
 
 pub mod day1;
 pub mod day10;
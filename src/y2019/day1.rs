@@ -1,3 +1,5 @@
+crate::day::impl_day!(Vec<i32>);
+
 pub fn part1(module_masses: &[i32]) -> i32 {
     sum_fuel(module_masses, fuel_required)
 }
@@ -3,6 +3,8 @@ use crate::grid::Pos;
 use ordered_float::NotNan;
 use std::collections::HashSet;
 
+crate::day::impl_day!(Input);
+
 pub struct Input {
     asteroids: Vec<Pos>,
     best_station_position: Pos,
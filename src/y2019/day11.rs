@@ -1,7 +1,10 @@
 use super::intcode::Vm;
+use crate::day::Answer;
 use crate::grid::{Compass, ORIGIN, Pos, SparseGrid};
+use crate::ocr;
 use crate::pixel_buffer::PixelBuffer;
-use std::fmt;
+
+crate::day::impl_day!(Vm);
 
 pub fn parse_input(input: &str) -> Vm {
     Vm::from(input)
@@ -10,14 +13,14 @@ pub fn parse_input(input: &str) -> Vm {
 pub fn part1(vm: &Vm) -> u32 {
     let mut hull = new_hull(Paint::Black);
     paint(vm, &mut hull);
-    // hull.to_string()
+    // to_pixel_buffer(&hull).to_string()
     hull.len()
 }
 
-pub fn part2(vm: &Vm) -> String {
+pub fn part2(vm: &Vm) -> Answer {
     let mut hull = new_hull(Paint::White);
     paint(vm, &mut hull);
-    hull.to_string()
+    ocr::answer(&to_pixel_buffer(&hull))
 }
 
 fn new_hull(start: Paint) -> SparseGrid<Paint> {
@@ -88,22 +91,20 @@ fn paint(paint_vm: &Vm, hull: &mut SparseGrid<Paint>) {
     }
 }
 
-impl fmt::Display for SparseGrid<Paint> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let mut buffer = PixelBuffer::new(self.width(), self.height());
-        for p in self.iter().filter_map(|(p, paint)| {
-            if *paint == Paint::White {
-                Some(p)
-            } else {
-                None
-            }
-        }) {
-            buffer.set(
-                (p.x - self.minx()).try_into().unwrap(),
-                (p.y - self.miny()).try_into().unwrap(),
-                true,
-            )
+fn to_pixel_buffer(hull: &SparseGrid<Paint>) -> PixelBuffer {
+    let mut buffer = PixelBuffer::new(hull.width(), hull.height());
+    for p in hull.iter().filter_map(|(p, paint)| {
+        if *paint == Paint::White {
+            Some(p)
+        } else {
+            None
         }
-        write!(f, "{}", buffer)
+    }) {
+        buffer.set(
+            (p.x - hull.minx()).try_into().unwrap(),
+            (p.y - hull.miny()).try_into().unwrap(),
+            true,
+        )
     }
+    buffer
 }
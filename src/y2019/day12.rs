@@ -1,8 +1,11 @@
 use num_integer::lcm;
-use std::cmp::Ordering;
 use std::fmt;
 
-pub fn part1(moons: &[Moon]) -> u16 {
+use crate::space::{ORIGIN3, Pos3};
+
+crate::day::impl_day!(Vec<Moon>);
+
+pub fn part1(moons: &[Moon]) -> u32 {
     let mut system = System::new(moons.to_owned());
     system.step(1000);
     system.total_energy()
@@ -33,19 +36,15 @@ impl System {
     fn apply_gravity(&mut self) {
         for a in 0..self.moons.len() {
             for b in (a + 1)..self.moons.len() {
-                let (gx, gy, gz) = self.moons[a].calc_gravity(&self.moons[b]);
-                self.moons[a].dx -= gx;
-                self.moons[b].dx += gx;
-                self.moons[a].dy -= gy;
-                self.moons[b].dy += gy;
-                self.moons[a].dz -= gz;
-                self.moons[b].dz += gz;
+                let gravity = self.moons[a].calc_gravity(&self.moons[b]);
+                self.moons[a].vel -= gravity;
+                self.moons[b].vel += gravity;
             }
         }
         self.moons.iter_mut().for_each(|moon| moon.step());
     }
 
-    fn total_energy(&self) -> u16 {
+    fn total_energy(&self) -> u32 {
         self.moons.iter().map(|moon| moon.total_energy()).sum()
     }
 
@@ -72,24 +71,24 @@ impl System {
         )
     }
 
-    fn state_x(&self) -> Vec<i16> {
+    fn state_x(&self) -> Vec<i32> {
         self.moons
             .iter()
-            .flat_map(|moon| [moon.x, moon.dx])
+            .flat_map(|moon| [moon.pos.x, moon.vel.x])
             .collect()
     }
 
-    fn state_y(&self) -> Vec<i16> {
+    fn state_y(&self) -> Vec<i32> {
         self.moons
             .iter()
-            .flat_map(|moon| [moon.y, moon.dy])
+            .flat_map(|moon| [moon.pos.y, moon.vel.y])
             .collect()
     }
 
-    fn state_z(&self) -> Vec<i16> {
+    fn state_z(&self) -> Vec<i32> {
         self.moons
             .iter()
-            .flat_map(|moon| [moon.z, moon.dz])
+            .flat_map(|moon| [moon.pos.z, moon.vel.z])
             .collect()
     }
 }
@@ -101,7 +100,7 @@ impl fmt::Debug for System {
             writeln!(
                 f,
                 "pos=<x={}, y={}, z={}>, vel=<x={}, y={}, z={}>",
-                moon.x, moon.y, moon.z, moon.dx, moon.dy, moon.dz
+                moon.pos.x, moon.pos.y, moon.pos.z, moon.vel.x, moon.vel.y, moon.vel.z
             )?;
         }
         Ok(())
@@ -110,50 +109,30 @@ impl fmt::Debug for System {
 
 #[derive(Clone)]
 pub struct Moon {
-    // position
-    x: i16,
-    y: i16,
-    z: i16,
-    //velocity
-    dx: i16,
-    dy: i16,
-    dz: i16,
+    pos: Pos3,
+    vel: Pos3,
 }
 
 impl Moon {
-    fn calc_gravity(&self, other: &Moon) -> (i16, i16, i16) {
-        (
-            compare(self.x, other.x),
-            compare(self.y, other.y),
-            compare(self.z, other.z),
-        )
+    // -1, 0 or 1 on each axis, like <=> operator
+    fn calc_gravity(&self, other: &Moon) -> Pos3 {
+        (self.pos - other.pos).signum()
     }
 
     fn step(&mut self) {
-        self.x += self.dx;
-        self.y += self.dy;
-        self.z += self.dz;
+        self.pos += self.vel;
     }
 
-    fn total_energy(&self) -> u16 {
+    fn total_energy(&self) -> u32 {
         self.potential_energy() * self.kinetic_energy()
     }
 
-    fn potential_energy(&self) -> u16 {
-        self.x.unsigned_abs() + self.y.unsigned_abs() + self.z.unsigned_abs()
+    fn potential_energy(&self) -> u32 {
+        self.pos.manhattan_distance(&ORIGIN3)
     }
 
-    fn kinetic_energy(&self) -> u16 {
-        self.dx.unsigned_abs() + self.dy.unsigned_abs() + self.dz.unsigned_abs()
-    }
-}
-
-// returns -1, 0 or 1, like <=> operator
-fn compare(a: i16, b: i16) -> i16 {
-    match a.cmp(&b) {
-        Ordering::Less => -1,
-        Ordering::Equal => 0,
-        Ordering::Greater => 1,
+    fn kinetic_energy(&self) -> u32 {
+        self.vel.manhattan_distance(&ORIGIN3)
     }
 }
 
@@ -165,12 +144,8 @@ impl From<&str> for Moon {
             .map(|ex| ex.split('=').next_back().unwrap().parse().unwrap())
             .collect();
         Moon {
-            x: coords[0],
-            y: coords[1],
-            z: coords[2],
-            dx: 0,
-            dy: 0,
-            dz: 0,
+            pos: Pos3::new(coords[0], coords[1], coords[2]),
+            vel: ORIGIN3,
         }
     }
 }
@@ -188,21 +163,13 @@ fn test_moon_system() {
 <x=3, y=5, z=-1>
 ";
     let moons = parse_input(test_input);
-    assert_eq!(-1, moons[0].x);
-    assert_eq!(0, moons[0].y);
-    assert_eq!(2, moons[0].z);
-    assert_eq!(0, moons[0].dx);
-    assert_eq!(0, moons[0].dy);
-    assert_eq!(0, moons[0].dz);
+    assert_eq!(Pos3::new(-1, 0, 2), moons[0].pos);
+    assert_eq!(ORIGIN3, moons[0].vel);
 
     let mut system = System::new(moons);
     system.step(1);
-    assert_eq!(2, system.moons[0].x);
-    assert_eq!(-1, system.moons[0].y);
-    assert_eq!(1, system.moons[0].z);
-    assert_eq!(3, system.moons[0].dx);
-    assert_eq!(-1, system.moons[0].dy);
-    assert_eq!(-1, system.moons[0].dz);
+    assert_eq!(Pos3::new(2, -1, 1), system.moons[0].pos);
+    assert_eq!(Pos3::new(3, -1, -1), system.moons[0].vel);
 
     system.step(9);
     assert_eq!(179, system.total_energy())
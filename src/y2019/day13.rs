@@ -1,9 +1,12 @@
 use super::intcode::Vm;
 use crate::grid::{Grid, Pos};
+use crate::options::RunOptions;
 use core::fmt;
 use std::cmp::Ordering;
 use std::{thread, time};
 
+crate::day::impl_day!(Vm);
+
 pub fn parse_input(input: &str) -> Vm {
     Vm::from(input)
 }
@@ -16,7 +19,7 @@ pub fn part1(arcade_vm: &Vm) -> usize {
 
 pub fn part2(arcade_vm: &Vm) -> i64 {
     let mut game = Game::new(arcade_vm.clone());
-    game.play(false); // change to true to watch game play
+    game.play(RunOptions::get().visualise); // watch game play with --visualise
     game.score
 }
 
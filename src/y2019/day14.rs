@@ -1,5 +1,7 @@
 use std::fmt;
 
+crate::day::impl_day!(Vec<Recipe>);
+
 pub fn part1(recipes: &[Recipe]) -> u64 {
     Nanofactory::new(recipes).ore_needed(FUEL, 1)
 }
@@ -1,6 +1,9 @@
 use super::intcode::Vm;
 use crate::bfs;
 use crate::grid::{Compass, ORIGIN, Pos, SparseGrid};
+use crate::render::{Render, View};
+
+crate::day::impl_day!(SparseGrid<Cell>);
 
 pub fn parse_input(input: &str) -> SparseGrid<Cell> {
     // pre-explored map of the ship
@@ -46,16 +49,22 @@ pub enum Cell {
     Oxygen,
 }
 
+impl Render for Cell {
+    fn to_char(&self) -> char {
+        match self {
+            Cell::Unknown => '·',
+            Cell::Wall => '█',
+            Cell::Oxygen => 'O',
+            Cell::Empty => ' ',
+        }
+    }
+}
+
 fn next_steps(ship: &SparseGrid<Cell>, from: &Pos) -> Vec<(Pos, Compass)> {
-    [
-        (from.step(Compass::North), Compass::North),
-        (from.step(Compass::South), Compass::South),
-        (from.step(Compass::West), Compass::West),
-        (from.step(Compass::East), Compass::East),
-    ]
-    .into_iter()
-    .filter(|(p, _d)| *ship.get(*p) != Cell::Wall)
-    .collect()
+    ship.neighbours4(*from)
+        .filter(|(_, _, cell)| **cell != Cell::Wall)
+        .map(|(p, dir, _)| (p, dir))
+        .collect()
 }
 
 // Explore ship
@@ -107,24 +116,8 @@ fn explore_ship(mut program: Vm) -> SparseGrid<Cell> {
 
 #[allow(dead_code)]
 fn draw_ship(ship: &SparseGrid<Cell>, robot: Pos) -> String {
-    let mut output = "".to_string();
-    for y in ship.miny()..=ship.maxy() {
-        for x in ship.minx()..=ship.maxx() {
-            let pos = (x, y).into();
-            output.push(if pos == robot {
-                'R'
-            } else if pos == ORIGIN {
-                '*'
-            } else {
-                match ship.get(pos) {
-                    Cell::Unknown => '·',
-                    Cell::Wall => '█',
-                    Cell::Oxygen => 'O',
-                    Cell::Empty => ' ',
-                }
-            });
-        }
-        output.push('\n');
-    }
-    output
+    View::new(ship)
+        .overlay(ORIGIN, '*')
+        .overlay(robot, 'R')
+        .to_string()
 }
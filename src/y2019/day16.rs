@@ -1,3 +1,5 @@
+crate::day::impl_day!(Vec<u8>);
+
 pub fn part1(input_signal: &[u8]) -> u32 {
     repeated_phase(input_signal)
 }
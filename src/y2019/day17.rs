@@ -1,14 +1,17 @@
 use super::intcode::Vm;
 use crate::grid::{Compass, Compass::*, Grid, ORIGIN, Pos};
+use crate::options::RunOptions;
 use std::fmt;
 use std::ops::Range;
 
+crate::day::impl_day!(Input);
+
 pub struct Input {
     vm: Vm,
     map: Grid<Cell>,
     robot_start_pos: Pos,
     robot_start_dir: Compass,
-    debug: bool, // if true, display all output from the program
+    debug: bool, // if true (--debug), display all output from the program
 }
 
 pub fn parse_input(input: &str) -> Input {
@@ -58,7 +61,7 @@ pub fn parse_input(input: &str) -> Input {
         map,
         robot_start_pos,
         robot_start_dir: robot_start_dir.unwrap(),
-        debug: false,
+        debug: RunOptions::get().debug,
     }
 }
 
@@ -1,7 +1,10 @@
 use crate::grid::{Compass, Grid, Pos};
+use crate::render::{Render, View};
 use crate::{bfs, dijkstra};
 use std::fmt;
 
+crate::day::impl_day!(Maze);
+
 pub fn part1(maze: &Maze) -> u32 {
     Search::new(maze).shortest_path().unwrap()
 }
@@ -46,15 +49,11 @@ pub struct Maze {
 
 impl Maze {
     fn neighbours(&self, from: &Pos) -> Vec<(Pos, Compass)> {
-        [
-            (from.step(Compass::North), Compass::North),
-            (from.step(Compass::East), Compass::East),
-            (from.step(Compass::South), Compass::South),
-            (from.step(Compass::West), Compass::West),
-        ]
-        .into_iter()
-        .filter(|(p, _d)| *self.grid.get(*p) != Cell::Wall)
-        .collect()
+        self.grid
+            .neighbours4(*from)
+            .filter(|(_, _, cell)| **cell != Cell::Wall)
+            .map(|(p, dir, _)| (p, dir))
+            .collect()
     }
 }
 
@@ -272,29 +271,21 @@ enum Cell {
     Door(u8), // A = 0, B = 1, etc
 }
 
+impl Render for Cell {
+    fn to_char(&self) -> char {
+        match self {
+            Cell::Wall => '#',
+            Cell::Passage => '.',
+            Cell::Key(k) => (k + b'a') as char,
+            Cell::Door(d) => (d + b'A') as char,
+        }
+    }
+}
+
 impl fmt::Display for Maze {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        for (p, c) in self.grid.iter() {
-            write!(
-                f,
-                "{}",
-                match c {
-                    Cell::Wall => '#',
-                    Cell::Passage =>
-                        if self.entrances.contains(&p) {
-                            '@'
-                        } else {
-                            '.'
-                        },
-                    Cell::Key(k) => (k + b'a') as char,
-                    Cell::Door(d) => (d + b'A') as char,
-                }
-            )?;
-            if p.x == self.grid.maxx() {
-                writeln!(f)?;
-            }
-        }
-        Ok(())
+        let view = View::new(&self.grid).overlay_all(self.entrances.iter().copied(), '@');
+        write!(f, "{}", view)
     }
 }
 
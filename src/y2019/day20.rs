@@ -5,6 +5,8 @@ use crate::{
 use Cell::*;
 use std::collections::HashMap;
 
+crate::day::impl_day!(Maze);
+
 pub fn part1(maze: &Maze) -> usize {
     solve(maze, false).unwrap()
 }
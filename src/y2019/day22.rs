@@ -1,6 +1,8 @@
 use crate::numeric::mod_inv;
 use std::fmt;
 
+crate::day::impl_day!(Vec<Technique>);
+
 pub fn part1(techniques: &[Technique]) -> i128 {
     compose_techniques(techniques, 10007).apply(2019)
 }
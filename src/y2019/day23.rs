@@ -2,6 +2,8 @@ use std::collections::HashSet;
 
 use super::intcode::Vm;
 
+crate::day::impl_day!(Vm);
+
 pub fn parse_input(input: &str) -> Vm {
     Vm::from(input)
 }
@@ -3,6 +3,8 @@ use std::collections::{HashSet, VecDeque};
 
 use crate::grid::parse_each_char;
 
+crate::day::impl_day!(BitGrid);
+
 pub fn parse_input(input: &str) -> BitGrid {
     BitGrid::from(input)
 }
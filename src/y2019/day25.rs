@@ -1,31 +1,28 @@
 use super::intcode::Vm;
+use crate::day::Answer;
+use crate::options::RunOptions;
 use itertools::Itertools;
 use std::collections::HashSet;
 use std::io;
 
-pub struct Input {
-    vm: Vm,
-    interactive: bool,
-}
+crate::day::impl_day!(Vm);
 
-pub fn parse_input(input: &str) -> Input {
-    Input {
-        vm: Vm::from(input),
-        interactive: false, // true to play manually, false to solve automatically
-    }
+pub fn parse_input(input: &str) -> Vm {
+    Vm::from(input)
 }
 
-pub fn part1(input: &Input) -> String {
-    if input.interactive {
-        interactive_mode(input.vm.clone());
+// with --interactive, play manually before solving automatically
+pub fn part1(input: &Vm) -> String {
+    if RunOptions::get().interactive {
+        interactive_mode(input.clone());
     }
-    let mut vm = input.vm.clone();
+    let mut vm = input.clone();
     gather_stuff(&mut vm);
     find_code(&mut vm)
 }
 
-pub fn part2(_input: &Input) -> &str {
-    "n/a"
+pub fn part2(_input: &Vm) -> Answer {
+    Answer::NotApplicable
 }
 
 fn interactive_mode(mut vm: Vm) {
@@ -1,5 +1,7 @@
 use std::collections::HashMap;
 
+crate::day::impl_day!(Input);
+
 #[derive(Debug)]
 pub struct Input {
     wire0: Wire,
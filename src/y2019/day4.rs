@@ -1,3 +1,5 @@
+crate::day::impl_day!(Input);
+
 pub struct Input {
     min: u32,
     max: u32,
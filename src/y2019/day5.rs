@@ -1,5 +1,7 @@
 use super::intcode::Vm;
 
+crate::day::impl_day!(Vm);
+
 pub fn parse_input(input: &str) -> Vm {
     Vm::from(input)
 }
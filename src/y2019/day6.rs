@@ -1,5 +1,7 @@
 use std::collections::HashMap;
 
+crate::day::impl_day!(OrbitMap);
+
 pub fn part1(map: &OrbitMap) -> u32 {
     count_all_orbits(&map.orbits)
 }
@@ -1,6 +1,8 @@
 use super::intcode::Vm;
 use itertools::Itertools;
 
+crate::day::impl_day!(Vm);
+
 pub fn parse_input(input: &str) -> Vm {
     Vm::from(input)
 }
@@ -1,7 +1,11 @@
 use std::fmt;
 
+use crate::day::Answer;
+use crate::ocr;
 use crate::pixel_buffer::PixelBuffer;
 
+crate::day::impl_day!(Image);
+
 pub fn parse_input(input: &str) -> Image {
     Image::new(input, 25, 6)
 }
@@ -16,8 +20,8 @@ pub fn part1(image: &Image) -> usize {
     count_pixels(layer, 1) * count_pixels(layer, 2)
 }
 
-pub fn part2(image: &Image) -> String {
-    image.to_string()
+pub fn part2(image: &Image) -> Answer {
+    ocr::answer(&image.to_pixel_buffer())
 }
 
 fn count_pixels(layer: &[u8], p: u8) -> usize {
@@ -50,6 +54,16 @@ impl Image {
         self.bytes[(x + y * self.width + layer * self.area) as usize]
     }
 
+    fn to_pixel_buffer(&self) -> PixelBuffer {
+        let mut buffer = PixelBuffer::new(self.width, self.height);
+        for y in 0..self.height {
+            for x in 0..self.width {
+                buffer.set(x, y, self.is_lit(x, y));
+            }
+        }
+        buffer
+    }
+
     fn is_lit(&self, x: u32, y: u32) -> bool {
         for layer in 0..(self.bytes.len() as u32 / self.area) {
             match self.get(x, y, layer) {
@@ -64,13 +78,7 @@ impl Image {
 
 impl fmt::Display for Image {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let mut buffer = PixelBuffer::new(self.width, self.height);
-        for y in 0..self.height {
-            for x in 0..self.width {
-                buffer.set(x, y, self.is_lit(x, y));
-            }
-        }
-        f.write_str(&buffer.to_string())
+        f.write_str(&self.to_pixel_buffer().to_string())
     }
 }
 
@@ -1,3 +1,5 @@
+crate::day::impl_day!(Vec<String>);
+
 fn illegal_char_score(c: char) -> usize {
     match c {
         ')' => 3,
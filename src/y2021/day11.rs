@@ -1,5 +1,7 @@
 use std::fmt;
 
+crate::day::impl_day!(Grid);
+
 pub fn parse_input(input: &str) -> Grid {
     let mut grid = Grid::new(input);
     for _ in 0..100 {
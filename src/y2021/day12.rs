@@ -1,3 +1,5 @@
+crate::day::impl_day!(CaveSystem);
+
 pub fn part1(system: &CaveSystem) -> usize {
     system.count_paths(&mut vec![0], Revisit::NotAllowed)
 }
@@ -1,7 +1,11 @@
 use std::collections::HashSet;
 
+use crate::day::Answer;
+use crate::ocr;
 use crate::pixel_buffer::PixelBuffer;
 
+crate::day::impl_day!(Input);
+
 pub struct Input {
     paper: Paper,
     folds: Vec<Fold>,
@@ -11,12 +15,12 @@ pub fn part1(input: &Input) -> usize {
     fold_paper(&input.paper, input.folds[0]).len()
 }
 
-pub fn part2(input: &Input) -> String {
+pub fn part2(input: &Input) -> Answer {
     let mut paper = input.paper.clone();
     for fold in input.folds.clone() {
         paper = fold_paper(&paper, fold);
     }
-    print_paper(&paper)
+    ocr::answer(&to_pixel_buffer(&paper))
 }
 
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -28,7 +32,7 @@ enum Fold {
 // set of dots are (row, column)
 type Paper = HashSet<(usize, usize)>;
 
-fn print_paper(paper: &Paper) -> String {
+fn to_pixel_buffer(paper: &Paper) -> PixelBuffer {
     let maxrow = *paper.iter().map(|(row, _)| row).max().unwrap() as u32;
     let maxcol = *paper.iter().map(|(_, col)| col).max().unwrap() as u32;
     let mut buffer = PixelBuffer::new(maxcol + 1, maxrow + 1);
@@ -39,7 +43,7 @@ fn print_paper(paper: &Paper) -> String {
             }
         }
     }
-    buffer.to_string()
+    buffer
 }
 
 fn fold_paper(paper: &Paper, fold: Fold) -> Paper {
@@ -130,6 +134,6 @@ fold along x=5
 ▛▀▌
 ▌ ▌
 ▀▀▘",
-        print_paper(&paper)
+        to_pixel_buffer(&paper).to_string()
     );
 }
@@ -1,5 +1,7 @@
 use std::collections::HashMap;
 
+crate::day::impl_day!(Input);
+
 pub struct Input {
     polymer: Polymer,
     rules: RuleSet,
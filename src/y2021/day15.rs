@@ -2,6 +2,10 @@ use std::cmp::Reverse;
 use std::collections::BinaryHeap;
 use std::collections::HashMap;
 
+use crate::grid::{Grid, Pos};
+
+crate::day::impl_day!(Cave);
+
 pub fn parse_input(input: &str) -> Cave {
     Cave::new(input)
 }
@@ -15,21 +19,17 @@ pub fn part2(cave: &Cave) -> usize {
     cave.lowest_risk()
 }
 
-#[derive(Debug)]
 pub struct Cave {
-    risks: Vec<Vec<usize>>,
+    risks: Grid<usize>,
     height: usize,
     width: usize,
 }
 
 impl Cave {
     fn new(input: &str) -> Cave {
-        let risks: Vec<Vec<usize>> = input
-            .lines()
-            .map(|line| line.chars().map(|c| c as usize - 48).collect())
-            .collect();
-        let height = risks.len();
-        let width = risks[0].len();
+        let risks = Grid::from_input(input, 0, |c| c as usize - 48);
+        let height = risks.height() as usize;
+        let width = risks.width() as usize;
         Cave {
             risks,
             height,
@@ -39,25 +39,12 @@ impl Cave {
 
     // Return a new cave 5 times bigger in each axis
     fn embiggen(&self) -> Cave {
-        let mut risks: Vec<Vec<usize>> = vec![vec![0; self.width * 5]; self.height * 5];
-        let height = self.height * 5;
-        let width = self.width * 5;
-
-        for tile_row in 0..5 {
-            for tile_col in 0..5 {
-                for row in 0..self.width {
-                    for col in 0..self.height {
-                        risks[self.height * tile_row + row][self.width * tile_col + col] =
-                            (self.risks[row][col] + tile_row + tile_col - 1) % 9 + 1;
-                    }
-                }
-            }
-        }
-
         Cave {
-            risks,
-            height,
-            width,
+            risks: self.risks.tile(5, 5, |tile_col, tile_row, risk| {
+                (risk + (tile_row + tile_col) as usize - 1) % 9 + 1
+            }),
+            height: self.height * 5,
+            width: self.width * 5,
         }
     }
 
@@ -79,7 +66,7 @@ impl Cave {
     }
 
     fn cost(&self, to: (usize, usize)) -> usize {
-        self.risks[to.0][to.1]
+        *self.risks.get(Pos::from((to.1, to.0)))
     }
 
     fn lowest_risk(&self) -> usize {
@@ -1,5 +1,7 @@
 use std::collections::VecDeque;
 
+crate::day::impl_day!(Packet);
+
 pub fn part1(packet: &Packet) -> u64 {
     packet.sum_versions()
 }
@@ -1,6 +1,8 @@
 use regex::Regex;
 use std::ops::Range;
 
+crate::day::impl_day!((i64, i64));
+
 pub fn parse_input(input: &str) -> (i64, i64) {
     search_shots(&parse_targets(input))
 }
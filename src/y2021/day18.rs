@@ -1,6 +1,8 @@
 use std::fmt;
 use std::ops::Add;
 
+crate::day::impl_day!(Vec<SfNumber>);
+
 pub fn part1(input: &[SfNumber]) -> u32 {
     add_sf_list(input).magnitude()
 }
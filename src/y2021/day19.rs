@@ -2,9 +2,13 @@ use std::collections::HashSet;
 use std::collections::VecDeque;
 use std::fmt;
 
+use crate::space::{ORIGIN3, Pos3};
+
+crate::day::impl_day!(Input);
+
 pub struct Input {
-    beacons: HashSet<Pos>,
-    scanners: Vec<Pos>,
+    beacons: HashSet<Pos3>,
+    scanners: Vec<Pos3>,
 }
 
 pub fn parse_input(input: &str) -> Input {
@@ -21,80 +25,10 @@ pub fn part2(input: &Input) -> usize {
     max_distance(&input.scanners)
 }
 
-#[derive(Clone, Copy, Eq, Hash, PartialEq)]
-struct Pos {
-    x: i32,
-    y: i32,
-    z: i32,
-}
-
-impl Pos {
-    fn new(x: i32, y: i32, z: i32) -> Pos {
-        Pos { x, y, z }
-    }
-
-    // each possible rotation from: http://www.euclideanspace.com/maths/algebra/matrix/transforms/examples/index.htm
-    fn rotate(&self, rot: i32) -> Pos {
-        match rot {
-            0 => Pos::new(self.x, self.y, self.z),
-            1 => Pos::new(self.x, self.z, -self.y),
-            2 => Pos::new(self.x, -self.y, -self.z),
-            3 => Pos::new(self.x, -self.z, self.y),
-            4 => Pos::new(self.y, -self.x, self.z),
-            5 => Pos::new(self.y, self.z, self.x),
-            6 => Pos::new(self.y, self.x, -self.z),
-            7 => Pos::new(self.y, -self.z, -self.x),
-            8 => Pos::new(-self.x, -self.y, self.z),
-            9 => Pos::new(-self.x, -self.z, -self.y),
-            10 => Pos::new(-self.x, self.y, -self.z), // scan0 -> scan1 from example
-            11 => Pos::new(-self.x, self.z, self.y),
-            12 => Pos::new(-self.y, self.x, self.z),
-            13 => Pos::new(-self.y, -self.z, self.x),
-            14 => Pos::new(-self.y, -self.x, -self.z),
-            15 => Pos::new(-self.y, self.z, -self.x),
-            16 => Pos::new(self.z, self.y, -self.x),
-            17 => Pos::new(self.z, self.x, self.y),
-            18 => Pos::new(self.z, -self.y, self.x),
-            19 => Pos::new(self.z, -self.x, -self.y),
-            20 => Pos::new(-self.z, -self.y, -self.x),
-            21 => Pos::new(-self.z, -self.x, self.y),
-            22 => Pos::new(-self.z, self.y, self.x),
-            23 => Pos::new(-self.z, self.x, -self.y),
-            _ => panic!(),
-        }
-    }
-
-    fn offset(&self, by: Pos) -> Pos {
-        Pos::new(self.x + by.x, self.y + by.y, self.z + by.z)
-    }
-
-    // manhattan distance between 2 positions
-    fn distance(&self, other: Pos) -> usize {
-        ((self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()) as usize
-    }
-}
-
-impl From<&str> for Pos {
-    fn from(s: &str) -> Self {
-        let pos: Vec<_> = s.split(',').map(|i| i.parse().unwrap()).collect();
-        Pos {
-            x: pos[0],
-            y: pos[1],
-            z: pos[2],
-        }
-    }
-}
-
-impl fmt::Debug for Pos {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{},{},{}", self.x, self.y, self.z)
-    }
-}
-
 #[derive(Clone, Debug)]
 struct Scan {
     number: usize,
-    beacons: Vec<Pos>,
+    beacons: Vec<Pos3>,
 }
 
 impl fmt::Display for Scan {
@@ -123,7 +57,7 @@ impl From<&str> for Scan {
                     .parse()
                     .unwrap();
             } else {
-                beacons.push(Pos::from(line));
+                beacons.push(line.parse().unwrap());
             }
         }
 
@@ -138,8 +72,8 @@ fn parse_scans(input: &str) -> Vec<Scan> {
 // for storing pre-rotated sets of beacons
 struct RotatedBeacons {
     scan_number: usize,
-    rotation: i32,
-    beacons: Vec<Pos>,
+    rotation: u8,
+    beacons: Vec<Pos3>,
 }
 
 impl fmt::Debug for RotatedBeacons {
@@ -155,11 +89,11 @@ impl fmt::Debug for RotatedBeacons {
 }
 
 // returns (set of beacons, vec of scanner positions)
-fn search(scans: &[Scan]) -> (HashSet<Pos>, Vec<Pos>) {
+fn search(scans: &[Scan]) -> (HashSet<Pos3>, Vec<Pos3>) {
     // everything will be relative to scan[0], so load its beacons into the
     // map straight away
     let mut beacons = HashSet::new();
-    let mut scanners: Vec<Pos> = scans.iter().map(|_| Pos::new(0, 0, 0)).collect();
+    let mut scanners: Vec<Pos3> = scans.iter().map(|_| ORIGIN3).collect();
     for beacon in &scans[0].beacons {
         beacons.insert(*beacon);
     }
@@ -167,7 +101,7 @@ fn search(scans: &[Scan]) -> (HashSet<Pos>, Vec<Pos>) {
     // queue of (scan_number, rot, rotated_beacons) for each scan/rotation pair
     let mut queue: VecDeque<RotatedBeacons> = VecDeque::new();
     for scan in scans.iter().skip(1) {
-        for rot in 0..24 {
+        for rot in 0..Pos3::ROTATIONS {
             queue.push_back(RotatedBeacons {
                 scan_number: scan.number,
                 rotation: rot,
@@ -177,7 +111,7 @@ fn search(scans: &[Scan]) -> (HashSet<Pos>, Vec<Pos>) {
     }
 
     // queue of known sets of points (re-orientated)
-    let mut known: Vec<HashSet<Pos>> =
+    let mut known: Vec<HashSet<Pos3>> =
         vec![HashSet::from_iter(scans[0].beacons.clone().into_iter())];
 
     while let Some(rotated_beacons) = queue.pop_front() {
@@ -194,12 +128,8 @@ fn search(scans: &[Scan]) -> (HashSet<Pos>, Vec<Pos>) {
                 // println!("MATCHED!  {:?} is at {:?}", rotated_beacons, scanner_pos);
                 matched = true;
                 // merge everything in this match into the set of known beacons
-                let set: HashSet<Pos> = HashSet::from_iter(
-                    rotated_beacons
-                        .beacons
-                        .iter()
-                        .map(|p| p.offset(scanner_pos)),
-                );
+                let set: HashSet<Pos3> =
+                    HashSet::from_iter(rotated_beacons.beacons.iter().map(|p| *p + scanner_pos));
                 beacons.extend(&set);
                 // store this set for later comparisons
                 known.push(set);
@@ -225,19 +155,15 @@ fn search(scans: &[Scan]) -> (HashSet<Pos>, Vec<Pos>) {
 // to be considered the correct rotation/offset.
 //
 // if found, returns the deduced scanner position.  otherwise returns None.
-fn match_beacons(rb: &RotatedBeacons, set: &HashSet<Pos>) -> Option<Pos> {
+fn match_beacons(rb: &RotatedBeacons, set: &HashSet<Pos3>) -> Option<Pos3> {
     // now try to guess the offset.  any pos in 'beacons' might map to any pos in `set`
     // but if it's not found by the time only 11 are left to check, this rotation will
     // not match.
     for i in 0..rb.beacons.len() - 11 {
         for known in set.iter() {
-            let offset = Pos::new(
-                known.x - rb.beacons[i].x,
-                known.y - rb.beacons[i].y,
-                known.z - rb.beacons[i].z,
-            );
+            let offset = *known - rb.beacons[i];
             let mut count = 0;
-            for b in rb.beacons.iter().map(|p| p.offset(offset)) {
+            for b in rb.beacons.iter().map(|p| *p + offset) {
                 if set.contains(&b) {
                     if count == 11 {
                         return Some(offset);
@@ -250,12 +176,12 @@ fn match_beacons(rb: &RotatedBeacons, set: &HashSet<Pos>) -> Option<Pos> {
     None
 }
 
-fn max_distance(scanners: &[Pos]) -> usize {
+fn max_distance(scanners: &[Pos3]) -> usize {
     let mut max = 0;
 
     for (i, p1) in scanners.iter().enumerate() {
         for p2 in scanners.iter().skip(i + 1) {
-            let dist = p1.distance(*p2);
+            let dist = p1.manhattan_distance(p2) as usize;
             if dist > max {
                 max = dist;
             }
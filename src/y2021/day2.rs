@@ -1,3 +1,5 @@
+crate::day::impl_day!(Vec<Command>);
+
 pub fn part1(commands: &[Command]) -> usize {
     let mut sub = Sub::new();
     sub.follow(commands);
@@ -2,6 +2,8 @@ use std::collections::HashSet;
 use std::fmt;
 use std::ops::Range;
 
+crate::day::impl_day!(Input);
+
 pub struct Input {
     alg: Vec<bool>,
     image: Image,
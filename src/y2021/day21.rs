@@ -2,6 +2,8 @@ use std::collections::HashMap;
 use std::iter::Cycle;
 use std::ops::RangeInclusive;
 
+crate::day::impl_day!(Game);
+
 pub fn parse_input(input: &str) -> Game {
     Game::from(input)
 }
@@ -3,6 +3,10 @@ use regex::Regex;
 use std::collections::HashMap;
 use std::collections::HashSet;
 
+use crate::space::Pos3;
+
+crate::day::impl_day!(Vec<Step>);
+
 pub fn part1(steps: &[Step]) -> usize {
     Core::new().reboot(&init_steps_only(steps))
 }
@@ -14,9 +18,8 @@ pub fn part2(steps: &[Step]) -> usize {
 #[derive(Clone, Debug)]
 pub struct Step {
     on: bool,
-    x: (i32, i32),
-    y: (i32, i32),
-    z: (i32, i32),
+    min: Pos3, // inclusive
+    max: Pos3,
 }
 
 impl From<&str> for Step {
@@ -29,9 +32,16 @@ impl From<&str> for Step {
         let m = RE.captures(s).unwrap();
         Self {
             on: &m[1] == "on",
-            x: (m[2].parse().unwrap(), m[3].parse().unwrap()),
-            y: (m[4].parse().unwrap(), m[5].parse().unwrap()),
-            z: (m[6].parse().unwrap(), m[7].parse().unwrap()),
+            min: Pos3::new(
+                m[2].parse().unwrap(),
+                m[4].parse().unwrap(),
+                m[6].parse().unwrap(),
+            ),
+            max: Pos3::new(
+                m[3].parse().unwrap(),
+                m[5].parse().unwrap(),
+                m[7].parse().unwrap(),
+            ),
         }
     }
 }
@@ -56,9 +66,9 @@ impl Core {
 
     #[allow(clippy::needless_range_loop)]
     fn reboot(&self, steps: &[Step]) -> usize {
-        let x_coords = build_coords(steps.iter().map(|step| step.x).collect());
-        let y_coords = build_coords(steps.iter().map(|step| step.y).collect());
-        let z_coords = build_coords(steps.iter().map(|step| step.z).collect());
+        let x_coords = build_coords(steps.iter().map(|step| (step.min.x, step.max.x)).collect());
+        let y_coords = build_coords(steps.iter().map(|step| (step.min.y, step.max.y)).collect());
+        let z_coords = build_coords(steps.iter().map(|step| (step.min.z, step.max.z)).collect());
 
         let x_map: HashMap<i32, usize> =
             HashMap::from_iter(x_coords.iter().enumerate().map(|(i, c)| (*c, i)));
@@ -70,12 +80,12 @@ impl Core {
         let mut cubes = vec![vec![vec![false; z_coords.len()]; y_coords.len()]; x_coords.len()];
 
         for step in steps {
-            let x0 = x_map[&step.x.0];
-            let x1 = x_map[&(step.x.1 + 1)];
-            let y0 = y_map[&step.y.0];
-            let y1 = y_map[&(step.y.1 + 1)];
-            let z0 = z_map[&step.z.0];
-            let z1 = z_map[&(step.z.1 + 1)];
+            let x0 = x_map[&step.min.x];
+            let x1 = x_map[&(step.max.x + 1)];
+            let y0 = y_map[&step.min.y];
+            let y1 = y_map[&(step.max.y + 1)];
+            let z0 = z_map[&step.min.z];
+            let z1 = z_map[&(step.max.z + 1)];
 
             for x in x0..x1 {
                 for y in y0..y1 {
@@ -112,19 +122,22 @@ fn init_steps_only(steps: &[Step]) -> Vec<Step> {
     let mut new = vec![];
 
     for step in steps {
-        if !(step.x.1 < -50
-            || step.x.0 > 50
-            || step.y.1 < -50
-            || step.y.0 > 50
-            || step.z.1 < -50
-            || step.z.0 > 50)
+        if !(step.max.x < -50
+            || step.min.x > 50
+            || step.max.y < -50
+            || step.min.y > 50
+            || step.max.z < -50
+            || step.min.z > 50)
         {
             // inside or partially inside the init procedure cube
             new.push(Step {
                 on: step.on,
-                x: (step.x.0.max(-50), step.x.1.min(50)),
-                y: (step.y.0.max(-50), step.y.1.min(50)),
-                z: (step.z.0.max(-50), step.z.1.min(50)),
+                min: Pos3::new(
+                    step.min.x.max(-50),
+                    step.min.y.max(-50),
+                    step.min.z.max(-50),
+                ),
+                max: Pos3::new(step.max.x.min(50), step.max.y.min(50), step.max.z.min(50)),
             })
         }
     }
@@ -3,6 +3,8 @@ use std::collections::BinaryHeap;
 use std::collections::HashMap;
 use std::fmt;
 
+crate::day::impl_day!(&'a str);
+
 pub fn parse_input(input: &str) -> &str {
     input
 }
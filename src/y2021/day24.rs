@@ -1,6 +1,8 @@
 use std::collections::HashMap;
 use std::fmt;
 
+crate::day::impl_day!(Vec<Inst>);
+
 pub fn part1(program: &[Inst]) -> i64 {
     solve(program, false)
 }
@@ -1,6 +1,10 @@
+use crate::day::Answer;
 use crate::grid::{Compass, Grid, Pos};
+use crate::render::{Render, View};
 use std::fmt;
 
+crate::day::impl_day!(&'a str);
+
 pub fn parse_input(input: &str) -> &str {
     input
 }
@@ -18,8 +22,8 @@ pub fn part1(input: &str) -> usize {
     step
 }
 
-pub fn part2(_input: &str) -> &str {
-    "n/a"
+pub fn part2(_input: &str) -> Answer {
+    Answer::NotApplicable
 }
 
 #[derive(Clone, PartialEq)]
@@ -85,32 +89,26 @@ impl Map {
     }
 }
 
+impl Render for Cucumber {
+    fn to_char(&self) -> char {
+        match self {
+            Cucumber::EastFacing => '>',
+            Cucumber::SouthFacing => 'v',
+            Cucumber::Empty => '.',
+        }
+    }
+}
+
 impl fmt::Display for Map {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let mut ef = 0;
-        let mut sf = 0;
-        for (p, c) in self.grid.iter() {
-            write!(
-                f,
-                "{}",
-                match c {
-                    Cucumber::EastFacing => {
-                        ef += 1;
-                        '>'
-                    }
-                    Cucumber::SouthFacing => {
-                        sf += 1;
-                        'v'
-                    }
-                    Cucumber::Empty => '.',
-                }
-            )?;
-            if p.x >= self.grid.maxx() {
-                writeln!(f)?;
-            }
-        }
-        writeln!(f, "{} EF, {} SF", ef, sf)?;
-        Ok(())
+        let count = |c| self.grid.iter().filter(|(_, v)| **v == c).count();
+        write!(f, "{}", View::new(&self.grid))?;
+        writeln!(
+            f,
+            "{} EF, {} SF",
+            count(Cucumber::EastFacing),
+            count(Cucumber::SouthFacing)
+        )
     }
 }
 
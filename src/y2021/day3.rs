@@ -1,3 +1,5 @@
+crate::day::impl_day!(Vec<usize>);
+
 pub fn part1(input: &[usize]) -> usize {
     let (gamma, epsilon) = calc_power_consumption(input);
     gamma * epsilon
@@ -1,5 +1,7 @@
 use std::fmt;
 
+crate::day::impl_day!(&'a str);
+
 pub fn parse_input(input: &str) -> &str {
     input
 }
@@ -2,6 +2,8 @@ use std::cmp::Ordering;
 use std::collections::HashMap;
 use std::fmt;
 
+crate::day::impl_day!(Vec<Line>);
+
 pub fn part1(lines: &[Line]) -> usize {
     count_overlapping_points(lines, CountFlag::WithoutDiagonals)
 }
@@ -1,3 +1,5 @@
+crate::day::impl_day!(Vec<usize>);
+
 pub fn part1(crabs: &[usize]) -> usize {
     least_fuel(crabs, measure_fuel).unwrap()
 }
@@ -1,3 +1,5 @@
+crate::day::impl_day!(Vec<Entry>);
+
 pub fn part1(entries: &[Entry]) -> usize {
     count_part1(entries)
 }
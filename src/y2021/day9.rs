@@ -1,7 +1,8 @@
-use crate::grid::Compass::*;
 use crate::grid::{Grid, Pos};
 use std::collections::HashSet;
 
+crate::day::impl_day!(Grid<u8>);
+
 pub fn parse_input(input: &str) -> Grid<u8> {
     Grid::from_input(input, 9, char_to_u8)
 }
@@ -17,11 +18,7 @@ pub fn part2(grid: &Grid<u8>) -> usize {
 impl Grid<u8> {
     fn lowpoints(&self) -> impl Iterator<Item = Pos> {
         self.iter().filter_map(|(p, value)| {
-            if self.look(p, North) > value
-                && self.look(p, East) > value
-                && self.look(p, South) > value
-                && self.look(p, West) > value
-            {
+            if self.neighbours4(p).all(|(_, _, other)| other > value) {
                 Some(p)
             } else {
                 None
@@ -38,9 +35,7 @@ impl Grid<u8> {
         seen.insert(from);
         let mut size = 1;
         let value = self.get(from);
-        for dir in [North, East, South, West] {
-            let to = from.step(dir);
-            let other = self.get(to);
+        for (to, _, other) in self.neighbours4(from) {
             if other >= value && *other != 9 && !seen.contains(&to) {
                 size += self.basin_size(to, seen);
             }
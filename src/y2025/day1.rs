@@ -1,3 +1,5 @@
+crate::day::impl_day!(Vec<i32>);
+
 pub fn part1(input: &[i32]) -> i32 {
     count_zeros(input, false)
 }
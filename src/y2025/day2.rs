@@ -1,3 +1,5 @@
+crate::day::impl_day!(Vec<(u64, u64)>);
+
 pub fn part1(input: &[(u64, u64)]) -> u64 {
     sum_invalid(input, invalid)
 }
@@ -1,3 +1,5 @@
+crate::day::impl_day!(Vec<Vec<u64>>);
+
 pub fn part1(banks: &[Vec<u64>]) -> u64 {
     sum_best_joltage(banks, 2)
 }
@@ -1,4 +1,6 @@
-use crate::grid::{ALL_DIRS, Grid, Pos};
+use crate::grid::{Grid, Pos};
+
+crate::day::impl_day!(Grid<Cell>);
 
 pub fn part1(grid: &Grid<Cell>) -> usize {
     accessible_paper(grid).len()
@@ -12,9 +14,9 @@ fn accessible_paper(grid: &Grid<Cell>) -> Vec<Pos> {
     let mut positions = vec![];
     for (pos, cell) in grid.iter() {
         if matches!(cell, Cell::Paper)
-            && ALL_DIRS
-                .iter()
-                .filter(|dir| matches!(grid.look(pos, **dir), Cell::Paper))
+            && grid
+                .neighbours8(pos)
+                .filter(|(_, _, cell)| matches!(cell, Cell::Paper))
                 .count()
                 < 4
         {
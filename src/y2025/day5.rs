@@ -1,3 +1,5 @@
+crate::day::impl_day!(Input);
+
 #[derive(Debug)]
 pub struct Input {
     fresh_ranges: Vec<(u64, u64)>,
@@ -1,3 +1,5 @@
+crate::day::impl_day!(Vec<Problem<'a>>);
+
 // split into columns.  the operator in the last line indicates the start of each column
 pub fn parse_input(input: &str) -> Vec<Problem<'_>> {
     let mut lines: Vec<&str> = input.lines().collect();